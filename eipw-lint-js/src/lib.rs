@@ -10,6 +10,8 @@ use eipw_lint::lints::DefaultLint;
 use eipw_lint::modifiers::DefaultModifier;
 use eipw_lint::reporters::{AdditionalHelp, Json};
 use eipw_lint::Linter;
+use eipw_snippets::annotate_snippets::Renderer;
+use eipw_snippets::Message;
 
 use js_sys::{JsString, Object};
 
@@ -60,7 +62,7 @@ impl Fetch for NodeFetch {
                 Ok(o) => Ok(o.as_string().unwrap()),
                 Err(e) => {
                     let txt = format!("{:?}", e);
-                    Err(std::io::Error::new(std::io::ErrorKind::Other, Error(txt)))
+                    Err(std::io::Error::other(Error(txt)))
                 }
             }
         };
@@ -94,7 +96,8 @@ impl Opts {
         }
 
         if !self.warn.is_empty() {
-            let defaults = DefaultOptions::<String>::default();
+            let mut defaults = DefaultOptions::<String>::default();
+            defaults.expand_preamble();
             let mut lints: HashMap<_, _> = defaults.lints;
             for warn in &self.warn {
                 let (k, v) = lints.remove_entry(warn.as_str()).unwrap();
@@ -103,7 +106,8 @@ impl Opts {
         }
 
         if !self.deny.is_empty() {
-            let defaults = DefaultOptions::<String>::default();
+            let mut defaults = DefaultOptions::<String>::default();
+            defaults.expand_preamble();
             let mut lints: HashMap<_, _> = defaults.lints;
             for deny in &self.deny {
                 let (k, v) = lints.remove_entry(deny.as_str()).unwrap();
@@ -178,6 +182,11 @@ pub fn format(snippet: &JsValue) -> Result<String, JsError> {
 
     match obj.get("formatted") {
         Some(serde_json::Value::String(s)) => Ok(s.into()),
-        _ => Err(JsError::new("expected `formatted` to be a string")),
+        Some(_) => Err(JsError::new("expected `formatted` to be a string")),
+        None => {
+            let message: Message<'static> = serde_json::from_value(serde_json::Value::Object(obj))?;
+            let rendered = Renderer::plain().render((&message).into()).to_string();
+            Ok(rendered)
+        }
     }
 }
@@ -66,6 +66,20 @@ async fn lint_one() {
                             "range": {
                                 "end": 12,
                                 "start": 9
+                            },
+                            "span": {
+                                "end": {
+                                    "column_chars": 13,
+                                    "column_utf16": 13,
+                                    "column_utf8": 13,
+                                    "line": 12
+                                },
+                                "start": {
+                                    "column_chars": 10,
+                                    "column_utf16": 10,
+                                    "column_utf8": 10,
+                                    "line": 12
+                                }
                             }
                         }
                     ],
@@ -184,6 +198,20 @@ async fn lint_json_schema() {
                             "range": {
                                 "end": 100,
                                 "start": 0
+                            },
+                            "span": {
+                                "end": {
+                                    "column_chars": 8,
+                                    "column_utf16": 8,
+                                    "column_utf8": 8,
+                                    "line": 48
+                                },
+                                "start": {
+                                    "column_chars": 1,
+                                    "column_utf16": 1,
+                                    "column_utf8": 1,
+                                    "line": 42
+                                }
                             }
                         },
                         {
@@ -192,6 +220,20 @@ async fn lint_json_schema() {
                             "range": {
                                 "end": 100,
                                 "start": 0
+                            },
+                            "span": {
+                                "end": {
+                                    "column_chars": 8,
+                                    "column_utf16": 8,
+                                    "column_utf8": 8,
+                                    "line": 48
+                                },
+                                "start": {
+                                    "column_chars": 1,
+                                    "column_utf16": 1,
+                                    "column_utf8": 1,
+                                    "line": 42
+                                }
                             }
                         }
                     ],
@@ -269,6 +311,20 @@ async fn lint_one_with_options() {
                             "range": {
                                 "end": 12,
                                 "start": 9
+                            },
+                            "span": {
+                                "end": {
+                                    "column_chars": 13,
+                                    "column_utf16": 13,
+                                    "column_utf8": 13,
+                                    "line": 12
+                                },
+                                "start": {
+                                    "column_chars": 10,
+                                    "column_utf16": 10,
+                                    "column_utf8": 10,
+                                    "line": 12
+                                }
                             }
                         }
                     ],
@@ -350,6 +406,20 @@ async fn lint_one_with_default_lints() {
                             "range": {
                                 "end": 12,
                                 "start": 9
+                            },
+                            "span": {
+                                "end": {
+                                    "column_chars": 13,
+                                    "column_utf16": 13,
+                                    "column_utf8": 13,
+                                    "line": 12
+                                },
+                                "start": {
+                                    "column_chars": 10,
+                                    "column_utf16": 10,
+                                    "column_utf8": 10,
+                                    "line": 12
+                                }
                             }
                         }
                     ],
@@ -429,6 +499,20 @@ async fn lint_one_with_default_modifiers() {
                             "range": {
                                 "end": 12,
                                 "start": 9
+                            },
+                            "span": {
+                                "end": {
+                                    "column_chars": 13,
+                                    "column_utf16": 13,
+                                    "column_utf8": 13,
+                                    "line": 12
+                                },
+                                "start": {
+                                    "column_chars": 10,
+                                    "column_utf16": 10,
+                                    "column_utf8": 10,
+                                    "line": 12
+                                }
                             }
                         }
                     ],
@@ -476,3 +560,28 @@ async fn format_one() {
 
     assert_eq!(expected, actual);
 }
+
+#[wasm_bindgen_test]
+async fn format_without_formatted() {
+    let mut path = PathBuf::from("tests");
+    path.push("eips");
+    path.push("eip-1000.md");
+
+    let path = path.to_str().unwrap();
+
+    let result = lint(vec![JsValue::from_str(path)], None)
+        .await
+        .ok()
+        .unwrap();
+
+    let mut snippets: Vec<serde_json::Value> = serde_wasm_bindgen::from_value(result).unwrap();
+    let expected = snippets[0]["formatted"].as_str().unwrap().to_owned();
+    snippets[0].as_object_mut().unwrap().remove("formatted");
+
+    let snippet = snippets[0]
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .unwrap();
+    let actual = format(&snippet).ok().unwrap();
+
+    assert_eq!(expected, actual);
+}
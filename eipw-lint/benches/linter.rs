@@ -0,0 +1,294 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Benchmarks [`Linter::run`] over a generated corpus of proposals, both with
+//! the default lints and with each lint on its own.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use eipw_lint::config::DefaultOptions;
+use eipw_lint::lints::{Context, Error, Lint};
+use eipw_lint::reporters::Null;
+use eipw_lint::Linter;
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use tokio::runtime::{Builder, Runtime};
+
+const PROPOSALS: u32 = 300;
+const FIRST_NUMBER: u32 = 1000;
+
+/// From most to least stable, so proposals only require ones at least as
+/// stable as themselves.
+const STATUSES: &[&str] = &["Final", "Last Call", "Review", "Draft", "Stagnant"];
+const CATEGORIES: &[&str] = &["Core", "Networking", "Interface", "ERC"];
+
+/// Proposals this one lists in `requires`, forming overlapping chains back to
+/// the start of the corpus.
+fn requires(number: u32) -> Vec<u32> {
+    [number.checked_sub(10), number.checked_sub(1)]
+        .into_iter()
+        .flatten()
+        .filter(|n| *n >= FIRST_NUMBER)
+        .collect()
+}
+
+fn category(number: u32) -> Option<&'static str> {
+    let index = (number - FIRST_NUMBER) as usize;
+    if index % 10 == 9 {
+        None
+    } else {
+        Some(CATEGORIES[index % CATEGORIES.len()])
+    }
+}
+
+fn prefix(number: u32) -> &'static str {
+    match category(number) {
+        Some("ERC") => "ERC",
+        _ => "EIP",
+    }
+}
+
+fn proposal(number: u32) -> String {
+    let index = (number - FIRST_NUMBER) as usize;
+    let status = STATUSES[index * STATUSES.len() / PROPOSALS as usize];
+    let requires = requires(number);
+
+    let mut src = String::new();
+
+    writeln!(src, "---").unwrap();
+    writeln!(src, "eip: {}", number).unwrap();
+    writeln!(src, "title: Synthetic proposal number {}", index).unwrap();
+    writeln!(
+        src,
+        "description: A generated proposal used to measure linting performance"
+    )
+    .unwrap();
+    writeln!(
+        src,
+        "author: John Doe (@johndoe), Jenny Doe <jenny.doe@example.com>"
+    )
+    .unwrap();
+    writeln!(
+        src,
+        "discussions-to: https://ethereum-magicians.org/t/synthetic/{}",
+        number
+    )
+    .unwrap();
+    writeln!(src, "status: {}", status).unwrap();
+    if status == "Last Call" {
+        writeln!(src, "last-call-deadline: 2020-02-01").unwrap();
+    }
+    match category(number) {
+        None => writeln!(src, "type: Informational").unwrap(),
+        Some(category) => {
+            writeln!(src, "type: Standards Track").unwrap();
+            writeln!(src, "category: {}", category).unwrap();
+        }
+    }
+    writeln!(src, "created: 2020-01-01").unwrap();
+    if !requires.is_empty() {
+        let requires: Vec<_> = requires.iter().map(u32::to_string).collect();
+        writeln!(src, "requires: {}", requires.join(", ")).unwrap();
+    }
+    writeln!(src, "---").unwrap();
+
+    writeln!(src, "\n## Abstract\n").unwrap();
+    writeln!(
+        src,
+        "This proposal describes a change to the protocol, and the reasons for \
+        making it.[^1] It is one of several related proposals."
+    )
+    .unwrap();
+
+    writeln!(src, "\n## Motivation\n").unwrap();
+    for n in &requires {
+        let p = prefix(*n);
+        writeln!(
+            src,
+            "This proposal builds on [{p}-{n}](./eip-{n}.md), which introduced the \
+            mechanism that is extended here. See also {p}-{n} for background.",
+        )
+        .unwrap();
+    }
+
+    writeln!(src, "\n## Specification\n").unwrap();
+    writeln!(
+        src,
+        "The key words \"MUST\", \"MUST NOT\", \"REQUIRED\", \"SHALL\", \"SHALL NOT\", \
+        \"SHOULD\", \"SHOULD NOT\", \"RECOMMENDED\", \"NOT RECOMMENDED\", \"MAY\", and \
+        \"OPTIONAL\" in this document are to be interpreted as described in \
+        [RFC 2119](https://www.rfc-editor.org/rfc/rfc2119) and \
+        [RFC 8174](https://www.rfc-editor.org/rfc/rfc8174)."
+    )
+    .unwrap();
+    writeln!(
+        src,
+        "\nImplementations MUST expose the following interface:\n"
+    )
+    .unwrap();
+    writeln!(src, "```solidity").unwrap();
+    writeln!(src, "interface ISynthetic{} {{", index).unwrap();
+    for method in 0..5 {
+        writeln!(
+            src,
+            "    function method{}(address owner, uint256 value) external returns (bool);",
+            method
+        )
+        .unwrap();
+    }
+    writeln!(src, "}}\n```").unwrap();
+    writeln!(
+        src,
+        "\n| Parameter | Value |\n|-----------|-------|\n| `LIMIT` | `{}` |\n| `DELAY` | `12` |",
+        number
+    )
+    .unwrap();
+
+    writeln!(src, "\n## Rationale\n").unwrap();
+    writeln!(
+        src,
+        "The design favors simplicity over flexibility. Alternatives were \
+        considered and rejected because they required changes to existing \
+        contracts. More details are in the [assets](../assets/eip-{}/README.md).",
+        number
+    )
+    .unwrap();
+
+    writeln!(src, "\n## Backwards Compatibility\n").unwrap();
+    writeln!(src, "No backward compatibility issues found.").unwrap();
+
+    writeln!(src, "\n## Security Considerations\n").unwrap();
+    writeln!(
+        src,
+        "Needs discussion. Implementers should take care to validate every \
+        input before acting on it."
+    )
+    .unwrap();
+
+    writeln!(src, "\n[^1]:").unwrap();
+    writeln!(src, "    ```csl-json").unwrap();
+    writeln!(src, "    {{").unwrap();
+    writeln!(src, "        \"type\": \"article\",").unwrap();
+    writeln!(src, "        \"id\": \"{}\",", index).unwrap();
+    writeln!(src, "        \"title\": \"A related paper\",").unwrap();
+    writeln!(
+        src,
+        "        \"author\": [{{ \"given\": \"Jane\", \"family\": \"Doe\" }}],"
+    )
+    .unwrap();
+    writeln!(
+        src,
+        "        \"issued\": {{ \"date-parts\": [[2020, 1, 1]] }},"
+    )
+    .unwrap();
+    writeln!(src, "        \"DOI\": \"10.1000/{}\",", index).unwrap();
+    writeln!(src, "        \"URL\": \"https://example.com/{}\"", index).unwrap();
+    writeln!(src, "    }}").unwrap();
+    writeln!(src, "    ```").unwrap();
+
+    writeln!(src, "\n## Copyright\n").unwrap();
+    writeln!(
+        src,
+        "Copyright and related rights waived via [CC0](../LICENSE.md)."
+    )
+    .unwrap();
+
+    src
+}
+
+/// Write the corpus to disk, so requires and links can be fetched like they
+/// would be in a real repository.
+fn corpus() -> Vec<PathBuf> {
+    let mut root = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    root.push("eipw-corpus");
+    root.push("EIPS");
+
+    std::fs::create_dir_all(&root).unwrap();
+
+    (FIRST_NUMBER..FIRST_NUMBER + PROPOSALS)
+        .map(|number| {
+            let path = root.join(format!("eip-{}.md", number));
+            std::fs::write(&path, proposal(number)).unwrap();
+            path
+        })
+        .collect()
+}
+
+/// Does nothing, so a run measures only fetching and parsing.
+#[derive(Debug)]
+struct Noop;
+
+impl Lint for Noop {
+    fn lint<'a>(&self, _slug: &'a str, _ctx: &Context<'a, '_>) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+fn runtime() -> Runtime {
+    Builder::new_current_thread().enable_all().build().unwrap()
+}
+
+async fn run<'a>(linter: Linter<'a, Null>, sources: &'a [PathBuf]) {
+    let linter = sources
+        .iter()
+        .fold(linter, |linter, source| linter.check_file(source));
+
+    linter.run().await.unwrap();
+}
+
+fn end_to_end(c: &mut Criterion) {
+    let sources = corpus();
+    let runtime = runtime();
+
+    let mut group = c.benchmark_group("end-to-end");
+    group.sample_size(10);
+
+    group.bench_function("default", |b| {
+        b.to_async(&runtime)
+            .iter(|| run(Linter::new(Null), &sources));
+    });
+
+    group.bench_function("parse", |b| {
+        b.to_async(&runtime)
+            .iter(|| run(Linter::new(Null).clear_lints().deny("noop", Noop), &sources));
+    });
+
+    group.finish();
+}
+
+fn per_lint(c: &mut Criterion) {
+    let sources = corpus();
+    let runtime = runtime();
+
+    let mut options = DefaultOptions::<String>::default();
+    options.expand_preamble();
+
+    let mut lints: Vec<_> = options
+        .lints
+        .into_iter()
+        .filter_map(|(slug, lint)| Some((slug, lint.into_lint()?)))
+        .collect();
+    lints.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut group = c.benchmark_group("lint");
+    group.sample_size(10);
+
+    for (slug, lint) in lints {
+        group.bench_with_input(BenchmarkId::from_parameter(&slug), &lint, |b, lint| {
+            b.to_async(&runtime).iter(|| {
+                let linter = Linter::new(Null).clear_lints().deny(&slug, lint.clone());
+                run(linter, &sources)
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, end_to_end, per_lint);
+criterion_main!(benches);
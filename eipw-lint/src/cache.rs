@@ -0,0 +1,200 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Diagnostics from earlier runs, reused when nothing that could change them
+//! has changed.
+
+use chrono::{NaiveDate, Utc};
+
+use eipw_snippets::Message;
+
+use olpc_cjson::CanonicalFormatter;
+
+use serde::{Deserialize, Serialize};
+
+use sha3::{Digest, Sha3_256};
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+use crate::reporters::{Error as ReporterError, Reporter};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Entries<K: Ord, M> {
+    #[serde(default = "BTreeMap::new")]
+    entries: BTreeMap<K, M>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    date: NaiveDate,
+    messages: Vec<Message<'static>>,
+}
+
+/// Per-file diagnostics, keyed by a hash of the eipw version, the linter
+/// configuration, the current date, the file's origin and contents, and the
+/// contents of every proposal fetched while linting it.
+///
+/// Some lints, like `preamble-timeline`, compare dates against today, so
+/// entries are only reused on the day they were recorded.
+///
+/// Entries from other versions or configurations are never matched, so one
+/// cache can safely be shared between them. [`Cache::save`] keeps every
+/// entry, except those recorded before the current date, which can no longer
+/// match.
+#[derive(Debug)]
+pub struct Cache {
+    config: Vec<u8>,
+    today: NaiveDate,
+    entries: RefCell<BTreeMap<String, Entry>>,
+    hits: Cell<usize>,
+    misses: Cell<usize>,
+}
+
+impl Cache {
+    /// An empty cache for linters configured with `config`.
+    ///
+    /// `config` must describe everything that affects which lints run and how
+    /// they behave, like the [`Options`](crate::config::Options) and any lints
+    /// allowed, warned or denied on top of them.
+    pub fn new<C>(config: &C) -> Result<Self, serde_json::Error>
+    where
+        C: Serialize,
+    {
+        let mut config_json = Vec::new();
+        let mut ser =
+            serde_json::Serializer::with_formatter(&mut config_json, CanonicalFormatter::new());
+        config.serialize(&mut ser)?;
+
+        Ok(Self {
+            config: Sha3_256::digest(&config_json).to_vec(),
+            today: Utc::now().date_naive(),
+            entries: Default::default(),
+            hits: Default::default(),
+            misses: Default::default(),
+        })
+    }
+
+    /// Treat `today` as the current date (in UTC) when matching entries.
+    pub fn set_today(&mut self, today: NaiveDate) {
+        self.today = today;
+    }
+
+    /// Add the entries previously written by [`Cache::save`].
+    pub fn load<R>(&mut self, reader: R) -> Result<(), serde_json::Error>
+    where
+        R: Read,
+    {
+        let loaded: Entries<String, Entry> = serde_json::from_reader(reader)?;
+        self.entries.get_mut().extend(loaded.entries);
+        Ok(())
+    }
+
+    /// Write the loaded and newly recorded entries, dropping any recorded
+    /// before the current date.
+    pub fn save<W>(&self, writer: W) -> Result<(), serde_json::Error>
+    where
+        W: Write,
+    {
+        let entries = self.entries.borrow();
+
+        let entries = Entries {
+            entries: entries
+                .iter()
+                .filter(|(_, e)| e.date >= self.today)
+                .collect::<BTreeMap<_, _>>(),
+        };
+
+        serde_json::to_writer(writer, &entries)
+    }
+
+    /// Number of files whose diagnostics were reused.
+    pub fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    /// Number of files that had to be linted.
+    pub fn misses(&self) -> usize {
+        self.misses.get()
+    }
+
+    pub(crate) fn key<'d, I>(&self, origin: Option<&str>, source: &str, dependencies: I) -> String
+    where
+        I: IntoIterator<Item = (u32, Option<&'d str>)>,
+    {
+        let mut hasher = Sha3_256::new();
+
+        let mut field = |bytes: &[u8]| {
+            hasher.update((bytes.len() as u64).to_le_bytes());
+            hasher.update(bytes);
+        };
+
+        field(env!("CARGO_PKG_VERSION").as_bytes());
+        field(&self.config);
+        field(self.today.to_string().as_bytes());
+        field(origin.unwrap_or_default().as_bytes());
+        field(source.as_bytes());
+
+        for (number, content) in dependencies {
+            field(&number.to_le_bytes());
+            match content {
+                Some(c) => field(c.as_bytes()),
+                None => field(&[]),
+            }
+        }
+
+        format!("{:x}", hasher.finalize())
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<Vec<Message<'static>>> {
+        let messages = self.entries.borrow().get(key).map(|e| e.messages.clone());
+
+        match messages {
+            Some(_) => self.hits.set(self.hits.get() + 1),
+            None => self.misses.set(self.misses.get() + 1),
+        }
+
+        messages
+    }
+
+    pub(crate) fn insert(&self, key: String, messages: Vec<Message<'static>>) {
+        let entry = Entry {
+            date: self.today,
+            messages,
+        };
+        self.entries.borrow_mut().insert(key, entry);
+    }
+}
+
+/// Forwards messages to another reporter, keeping a copy to cache.
+pub(crate) struct Recorder<'a> {
+    inner: &'a dyn Reporter,
+    messages: RefCell<Vec<Message<'static>>>,
+}
+
+impl<'a> Recorder<'a> {
+    pub(crate) fn new(inner: &'a dyn Reporter) -> Self {
+        Self {
+            inner,
+            messages: Default::default(),
+        }
+    }
+
+    /// Remove and return the messages recorded so far.
+    pub(crate) fn take(&self) -> Vec<Message<'static>> {
+        self.messages.take()
+    }
+}
+
+impl Reporter for Recorder<'_> {
+    fn report(&self, message: Message<'_>) -> Result<(), ReporterError> {
+        self.messages
+            .borrow_mut()
+            .push(message.clone().into_owned());
+        self.inner.report(message)
+    }
+}
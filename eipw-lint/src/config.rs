@@ -4,6 +4,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+pub mod preamble;
+
 use crate::lints::{DefaultLint, Lint};
 use crate::modifiers::{self, DefaultModifier, Modifier};
 use crate::Level;
@@ -30,6 +32,84 @@ fn default_modifiers() -> Vec<DefaultModifier<&'static str>> {
     ]
 }
 
+fn default_preamble() -> preamble::Schema<&'static str> {
+    use self::preamble::{Field, Kind, RequiredIf, Schema};
+
+    Schema::new(vec![
+        Field {
+            required: true,
+            kind: Some(Kind::Uint),
+            type_slug: Some("preamble-eip"),
+            ..Field::new("eip")
+        },
+        Field {
+            required: true,
+            min_length: Some(2),
+            max_length: Some(44),
+            ..Field::new("title")
+        },
+        Field {
+            required: true,
+            min_length: Some(2),
+            max_length: Some(140),
+            ..Field::new("description")
+        },
+        Field {
+            required: true,
+            kind: Some(Kind::Author),
+            type_slug: Some("preamble-author"),
+            ..Field::new("author")
+        },
+        Field {
+            required: true,
+            kind: Some(Kind::Url),
+            type_slug: Some("preamble-discussions-to"),
+            ..Field::new("discussions-to")
+        },
+        Field {
+            required: true,
+            kind: Some(Kind::Enum(vec![
+                "Draft",
+                "Review",
+                "Last Call",
+                "Final",
+                "Stagnant",
+                "Withdrawn",
+                "Living",
+            ])),
+            ..Field::new("status")
+        },
+        Field {
+            kind: Some(Kind::Date),
+            ..Field::new("last-call-deadline")
+        },
+        Field {
+            required: true,
+            kind: Some(Kind::Enum(vec!["Standards Track", "Meta", "Informational"])),
+            ..Field::new("type")
+        },
+        Field {
+            kind: Some(Kind::Enum(vec!["Core", "Networking", "Interface", "ERC"])),
+            required_if: Some(RequiredIf {
+                when: "type",
+                equals: "Standards Track",
+            }),
+            ..Field::new("category")
+        },
+        Field {
+            required: true,
+            kind: Some(Kind::Date),
+            ..Field::new("created")
+        },
+        Field {
+            kind: Some(Kind::UintList),
+            min_length: Some(1),
+            ..Field::new("requires")
+        },
+        Field::new("withdrawal-reason"),
+    ])
+}
+
 fn default_lints() -> impl Iterator<Item = (&'static str, DefaultLint<&'static str>)> {
     use crate::lints::preamble::regex;
     use crate::lints::DefaultLint::*;
@@ -41,8 +121,6 @@ fn default_lints() -> impl Iterator<Item = (&'static str, DefaultLint<&'static s
         //
         ("preamble-no-dup", PreambleNoDuplicates(preamble::NoDuplicates)),
         ("preamble-trim", PreambleTrim(preamble::Trim)),
-        ("preamble-eip", PreambleUint { name: preamble::Uint("eip") }),
-        ("preamble-author", PreambleAuthor { name: preamble::Author("author") } ),
         ("preamble-re-title", PreambleRegex(preamble::Regex {
             name: "title",
             mode: regex::Mode::Excludes,
@@ -123,10 +201,6 @@ fn default_lints() -> impl Iterator<Item = (&'static str, DefaultLint<&'static s
             pattern: r":",
             message: "preamble header `description` should not contain `:`",
         })),
-        (
-            "preamble-discussions-to",
-            PreambleUrl { name: preamble::Url("discussions-to") },
-        ),
         (
             "preamble-re-discussions-to",
             PreambleRegex(preamble::Regex {
@@ -139,135 +213,6 @@ fn default_lints() -> impl Iterator<Item = (&'static str, DefaultLint<&'static s
                 ),
             }),
         ),
-        ("preamble-list-author", PreambleList { name: preamble::List("author") }),
-        ("preamble-list-requires", PreambleList{name: preamble::List("requires")}),
-        (
-            "preamble-len-requires",
-            PreambleLength(preamble::Length {
-                name: "requires",
-                min: Some(1),
-                max: None,
-            }
-            ),
-        ),
-        (
-            "preamble-uint-requires",
-            PreambleUintList { name: preamble::UintList("requires") },
-        ),
-        (
-            "preamble-len-title",
-            PreambleLength(preamble::Length {
-                name: "title",
-                min: Some(2),
-                max: Some(44),
-            }
-            ),
-        ),
-        (
-            "preamble-len-description",
-            PreambleLength(preamble::Length {
-                name: "description",
-                min: Some(2),
-                max: Some(140),
-            }
-            ),
-        ),
-        (
-            "preamble-req",
-            PreambleRequired { names: preamble::Required(vec![
-                "eip",
-                "title",
-                "description",
-                "author",
-                "discussions-to",
-                "status",
-                "type",
-                "created",
-            ])
-            },
-        ),
-        (
-            "preamble-order",
-            PreambleOrder { names: preamble::Order(vec![
-                "eip",
-                "title",
-                "description",
-                "author",
-                "discussions-to",
-                "status",
-                "last-call-deadline",
-                "type",
-                "category",
-                "created",
-                "requires",
-                "withdrawal-reason",
-            ])
-            },
-        ),
-        ("preamble-date-created", PreambleDate { name: preamble::Date("created") } ),
-        (
-            "preamble-req-last-call-deadline",
-            PreambleRequiredIfEq(preamble::RequiredIfEq {
-                when: "status",
-                equals: "Last Call",
-                then: "last-call-deadline",
-            }
-            ),
-        ),
-        (
-            "preamble-date-last-call-deadline",
-            PreambleDate { name: preamble::Date("last-call-deadline") },
-        ),
-        (
-            "preamble-req-category",
-            PreambleRequiredIfEq(preamble::RequiredIfEq {
-                when: "type",
-                equals: "Standards Track",
-                then: "category",
-            }
-            ),
-        ),
-        (
-            "preamble-req-withdrawal-reason",
-            PreambleRequiredIfEq(preamble::RequiredIfEq {
-                when: "status",
-                equals: "Withdrawn",
-                then: "withdrawal-reason",
-            }
-            ),
-        ),
-        (
-            "preamble-enum-status",
-            PreambleOneOf(preamble::OneOf {
-                name: "status",
-                values: vec![
-                    "Draft",
-                    "Review",
-                    "Last Call",
-                    "Final",
-                    "Stagnant",
-                    "Withdrawn",
-                    "Living",
-                ],
-            }
-            ),
-        ),
-        (
-            "preamble-enum-type",
-            PreambleOneOf(preamble::OneOf {
-                name: "type",
-                values: vec!["Standards Track", "Meta", "Informational"],
-            }
-            ),
-        ),
-        (
-            "preamble-enum-category",
-            PreambleOneOf(preamble::OneOf {
-                name: "category",
-                values: vec!["Core", "Networking", "Interface", "ERC"],
-            }
-            ),
-        ),
         (
             "preamble-requires-status",
             PreambleRequiresStatus(preamble::RequiresStatus {
@@ -295,6 +240,50 @@ fn default_lints() -> impl Iterator<Item = (&'static str, DefaultLint<&'static s
                 requires: "requires",
             }),
         ),
+        (
+            "preamble-req-last-call-deadline",
+            PreambleCondition(preamble::Condition {
+                name: "last-call-deadline",
+                rule: preamble::condition::Rule::RequiredIf,
+                when: preamble::condition::Predicate::OneOf {
+                    name: "status",
+                    values: vec!["Last Call"],
+                },
+            }),
+        ),
+        (
+            "preamble-condition-last-call-deadline",
+            PreambleCondition(preamble::Condition {
+                name: "last-call-deadline",
+                rule: preamble::condition::Rule::ForbiddenUnless,
+                when: preamble::condition::Predicate::OneOf {
+                    name: "status",
+                    values: vec!["Last Call"],
+                },
+            }),
+        ),
+        (
+            "preamble-req-withdrawal-reason",
+            PreambleCondition(preamble::Condition {
+                name: "withdrawal-reason",
+                rule: preamble::condition::Rule::RequiredIf,
+                when: preamble::condition::Predicate::OneOf {
+                    name: "status",
+                    values: vec!["Withdrawn"],
+                },
+            }),
+        ),
+        (
+            "preamble-condition-withdrawal-reason",
+            PreambleCondition(preamble::Condition {
+                name: "withdrawal-reason",
+                rule: preamble::condition::Rule::ForbiddenUnless,
+                when: preamble::condition::Predicate::OneOf {
+                    name: "status",
+                    values: vec!["Withdrawn"],
+                },
+            }),
+        ),
         (
             "preamble-file-name",
             PreambleFileName(preamble::FileName {
@@ -680,6 +669,36 @@ pub struct Options<M, L> {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fetch: Option<FetchOptions>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preamble: Option<preamble::Schema<String>>,
+}
+
+impl<M, L> Options<M, L> {
+    /// Lints compiled from `preamble`, except those overridden in `lints`.
+    pub fn preamble_lints(&self) -> impl Iterator<Item = (String, DefaultLint<String>)> + '_ {
+        self.preamble
+            .iter()
+            .flat_map(preamble::Schema::compile)
+            .filter(|(slug, _)| !self.lints.contains_key(slug))
+            .map(|(slug, lint)| (slug, lint.into()))
+    }
+}
+
+impl<M, L> Options<M, L>
+where
+    DefaultLint<String>: Into<L>,
+{
+    /// Move the lints compiled from `preamble` into `lints`.
+    pub fn expand_preamble(&mut self) {
+        let compiled: Vec<_> = self.preamble_lints().collect();
+        self.lints.extend(
+            compiled
+                .into_iter()
+                .map(|(slug, lint)| (slug, Override::enable(lint.into()))),
+        );
+        self.preamble = None;
+    }
 }
 
 #[cfg(feature = "schema-version")]
@@ -747,6 +766,7 @@ where
                 .map(|(k, v)| (k.into(), Override::enable(v.into())))
                 .collect(),
             fetch: Some(FetchOptions::default()),
+            preamble: Some(default_preamble().into()),
         }
     }
 }
@@ -756,6 +776,7 @@ where
     M: 'static + Modifier,
     L: 'static + Lint,
 {
+    #[allow(clippy::type_complexity)]
     pub fn to_iters(
         self,
     ) -> (
@@ -763,6 +784,11 @@ where
         impl Iterator<Item = (String, Box<dyn Lint>)>,
         Option<FetchOptions>,
     ) {
+        let compiled: Vec<_> = self
+            .preamble_lints()
+            .map(|(k, v)| (k, Box::new(v) as Box<dyn Lint>))
+            .collect();
+
         let modifiers = self
             .modifiers
             .into_iter()
@@ -772,7 +798,8 @@ where
             .lints
             .into_iter()
             .filter_map(|(k, v)| Some((k, v.into_lint()?)))
-            .map(|(k, v)| (k, Box::new(v) as Box<dyn Lint>));
+            .map(|(k, v)| (k, Box::new(v) as Box<dyn Lint>))
+            .chain(compiled);
 
         (modifiers, lints, self.fetch)
     }
@@ -782,6 +809,7 @@ impl<M, L> Options<M, L>
 where
     Self: DeserializeOwned,
 {
+    #[allow(clippy::result_large_err)]
     pub fn from<T: Provider>(provider: T) -> Result<Self, figment::Error> {
         Figment::from(provider).extract()
     }
@@ -0,0 +1,352 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::lints::preamble;
+use crate::lints::DefaultLint;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-version", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum Kind<S> {
+    Uint,
+    UintList,
+    Date,
+    Url,
+    List,
+    Author,
+    Enum(Vec<S>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-version", derive(schemars::JsonSchema))]
+pub struct RequiredIf<S> {
+    pub when: S,
+    pub equals: S,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-version", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct Field<S> {
+    pub name: S,
+
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+
+    #[serde(
+        default = "Option::default",
+        rename = "type",
+        skip_serializing_if = "Option::<Kind<S>>::is_none"
+    )]
+    pub kind: Option<Kind<S>>,
+
+    /// Replaces the derived slug of the lint generated for `type`.
+    #[serde(
+        default = "Option::default",
+        skip_serializing_if = "Option::<S>::is_none"
+    )]
+    pub type_slug: Option<S>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    #[serde(
+        default = "Option::default",
+        skip_serializing_if = "Option::<RequiredIf<S>>::is_none"
+    )]
+    pub required_if: Option<RequiredIf<S>>,
+}
+
+impl<S> Field<S> {
+    pub fn new(name: S) -> Self {
+        Self {
+            name,
+            required: false,
+            kind: None,
+            type_slug: None,
+            min_length: None,
+            max_length: None,
+            required_if: None,
+        }
+    }
+}
+
+/// Every header allowed in the preamble, in the order they must appear.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-version", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Schema<S> {
+    #[serde(default = "Vec::<Field<S>>::new")]
+    pub fields: Vec<Field<S>>,
+}
+
+impl<S> Schema<S> {
+    pub fn new(fields: Vec<Field<S>>) -> Self {
+        Self { fields }
+    }
+}
+
+impl<S> Schema<S>
+where
+    S: AsRef<str>,
+{
+    /// Expand the schema into the equivalent individually configured lints.
+    pub fn compile(&self) -> Vec<(String, DefaultLint<&str>)> {
+        use crate::lints::DefaultLint::*;
+
+        if self.fields.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lints = Vec::new();
+
+        let required: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|f| f.required)
+            .map(|f| f.name.as_ref())
+            .collect();
+
+        if !required.is_empty() {
+            lints.push((
+                "preamble-req".to_string(),
+                PreambleRequired {
+                    names: preamble::Required(required),
+                },
+            ));
+        }
+
+        lints.push((
+            "preamble-order".to_string(),
+            PreambleOrder {
+                names: preamble::Order(self.fields.iter().map(|f| f.name.as_ref()).collect()),
+            },
+        ));
+
+        for field in &self.fields {
+            let name = field.name.as_ref();
+            let type_slug = |kind: &str| match &field.type_slug {
+                Some(s) => s.as_ref().to_string(),
+                None => format!("preamble-{}-{}", kind, name),
+            };
+
+            match &field.kind {
+                None => (),
+                Some(Kind::Uint) => lints.push((
+                    type_slug("uint"),
+                    PreambleUint {
+                        name: preamble::Uint(name),
+                    },
+                )),
+                Some(Kind::UintList) => {
+                    lints.push((
+                        format!("preamble-list-{}", name),
+                        PreambleList {
+                            name: preamble::List(name),
+                        },
+                    ));
+                    lints.push((
+                        type_slug("uint"),
+                        PreambleUintList {
+                            name: preamble::UintList(name),
+                        },
+                    ));
+                }
+                Some(Kind::Date) => lints.push((
+                    type_slug("date"),
+                    PreambleDate {
+                        name: preamble::Date(name),
+                    },
+                )),
+                Some(Kind::Url) => lints.push((
+                    type_slug("url"),
+                    PreambleUrl {
+                        name: preamble::Url(name),
+                    },
+                )),
+                Some(Kind::List) => lints.push((
+                    type_slug("list"),
+                    PreambleList {
+                        name: preamble::List(name),
+                    },
+                )),
+                Some(Kind::Author) => {
+                    lints.push((
+                        format!("preamble-list-{}", name),
+                        PreambleList {
+                            name: preamble::List(name),
+                        },
+                    ));
+                    lints.push((
+                        type_slug("author"),
+                        PreambleAuthor {
+                            name: preamble::Author(name),
+                        },
+                    ));
+                }
+                Some(Kind::Enum(values)) => lints.push((
+                    type_slug("enum"),
+                    PreambleOneOf(preamble::OneOf {
+                        name,
+                        values: values.iter().map(AsRef::as_ref).collect(),
+                    }),
+                )),
+            }
+
+            if field.min_length.is_some() || field.max_length.is_some() {
+                lints.push((
+                    format!("preamble-len-{}", name),
+                    PreambleLength(preamble::Length {
+                        name,
+                        min: field.min_length,
+                        max: field.max_length,
+                    }),
+                ));
+            }
+
+            if let Some(required_if) = &field.required_if {
+                lints.push((
+                    format!("preamble-req-{}", name),
+                    PreambleRequiredIfEq(preamble::RequiredIfEq {
+                        when: required_if.when.as_ref(),
+                        equals: required_if.equals.as_ref(),
+                        then: name,
+                    }),
+                ));
+            }
+        }
+
+        lints
+    }
+}
+
+impl From<Kind<&str>> for Kind<String> {
+    fn from(value: Kind<&str>) -> Self {
+        match value {
+            Kind::Uint => Kind::Uint,
+            Kind::UintList => Kind::UintList,
+            Kind::Date => Kind::Date,
+            Kind::Url => Kind::Url,
+            Kind::List => Kind::List,
+            Kind::Author => Kind::Author,
+            Kind::Enum(v) => Kind::Enum(v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<Field<&str>> for Field<String> {
+    fn from(value: Field<&str>) -> Self {
+        Self {
+            name: value.name.into(),
+            required: value.required,
+            kind: value.kind.map(Into::into),
+            type_slug: value.type_slug.map(Into::into),
+            min_length: value.min_length,
+            max_length: value.max_length,
+            required_if: value.required_if.map(|r| RequiredIf {
+                when: r.when.into(),
+                equals: r.equals.into(),
+            }),
+        }
+    }
+}
+
+impl From<Schema<&str>> for Schema<String> {
+    fn from(value: Schema<&str>) -> Self {
+        Self {
+            fields: value.fields.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::{DefaultOptions, Override};
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn compile_derives_slugs() {
+        let schema = Schema::new(vec![
+            Field {
+                required: true,
+                kind: Some(Kind::Uint),
+                ..Field::new("eip")
+            },
+            Field {
+                kind: Some(Kind::UintList),
+                min_length: Some(1),
+                ..Field::new("requires")
+            },
+            Field {
+                kind: Some(Kind::Date),
+                type_slug: Some("preamble-deadline"),
+                required_if: Some(RequiredIf {
+                    when: "status",
+                    equals: "Last Call",
+                }),
+                ..Field::new("last-call-deadline")
+            },
+        ]);
+
+        let actual: HashSet<_> = schema.compile().into_iter().map(|(s, _)| s).collect();
+        let expected: HashSet<_> = [
+            "preamble-req",
+            "preamble-order",
+            "preamble-uint-eip",
+            "preamble-list-requires",
+            "preamble-uint-requires",
+            "preamble-len-requires",
+            "preamble-deadline",
+            "preamble-req-last-call-deadline",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn compile_empty() {
+        let schema = Schema::<&str>::new(vec![]);
+        assert!(schema.compile().is_empty());
+    }
+
+    #[test]
+    fn schema_round_trip() {
+        let options = DefaultOptions::<String>::default();
+        let schema = options.preamble.unwrap();
+
+        let serialized = toml::to_string_pretty(&schema).unwrap();
+        let actual: Schema<String> = toml::from_str(&serialized).unwrap();
+
+        let expected: Vec<_> = schema.compile().into_iter().map(|(s, _)| s).collect();
+        let actual: Vec<_> = actual.compile().into_iter().map(|(s, _)| s).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn explicit_lints_take_precedence() {
+        let mut options = DefaultOptions::<String>::default();
+        options
+            .lints
+            .insert("preamble-enum-status".into(), Override::disable());
+
+        let slugs: HashSet<_> = options.preamble_lints().map(|(s, _)| s).collect();
+        assert!(!slugs.contains("preamble-enum-status"));
+        assert!(slugs.contains("preamble-enum-type"));
+    }
+}
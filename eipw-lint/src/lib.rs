@@ -4,11 +4,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+pub mod cache;
 pub mod config;
 pub mod fetch;
 pub mod lints;
 pub mod modifiers;
 pub mod reporters;
+#[cfg(feature = "testing")]
+#[doc(hidden)]
+pub mod testing;
+pub mod timings;
 pub mod tree;
 
 use config::Override;
@@ -21,10 +26,12 @@ use formatx::formatx;
 use lints::DefaultLint;
 use modifiers::DefaultModifier;
 
+use crate::cache::{Cache, Recorder};
 use crate::config::Options;
 use crate::lints::{Context, Error as LintError, FetchContext, InnerContext, Lint};
 use crate::modifiers::Modifier;
 use crate::reporters::Reporter;
+use crate::timings::{Stopwatch, Timings};
 
 use educe::Educe;
 
@@ -34,6 +41,7 @@ use snafu::{ensure, ResultExt, Snafu};
 
 use std::cell::RefCell;
 use std::collections::hash_map::{self, HashMap};
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
 #[derive(Snafu, Debug)]
@@ -117,6 +125,10 @@ pub struct Linter<'a, R> {
 
     #[educe(Debug(ignore))]
     fetch: Box<dyn fetch::Fetch>,
+
+    timings: Option<&'a Timings>,
+
+    cache: Option<&'a Cache>,
 }
 
 impl<'a, R> Default for Linter<'a, R>
@@ -134,12 +146,17 @@ impl<'a, R> Linter<'a, R> {
         L: 'static + Lint,
         M: 'static + Modifier,
     {
-        let lints = options
-            .lints
-            .into_iter()
-            .filter_map(|(slug, toggle)| Some((slug, (None, Box::new(toggle.into_lint()?) as _))))
+        let mut lints: HashMap<_, _> = options
+            .preamble_lints()
+            .map(|(slug, lint)| (slug, (None, Box::new(lint) as _)))
             .collect();
 
+        lints.extend(
+            options.lints.into_iter().filter_map(|(slug, toggle)| {
+                Some((slug, (None, Box::new(toggle.into_lint()?) as _)))
+            }),
+        );
+
         let proposal_format = options
             .fetch
             .map(|o| o.proposal_format)
@@ -156,6 +173,8 @@ impl<'a, R> Linter<'a, R> {
                 .collect(),
             lints,
             proposal_format,
+            timings: None,
+            cache: None,
         }
     }
 
@@ -172,6 +191,7 @@ impl<'a, R> Linter<'a, R> {
                 modifiers: modifiers.into_iter().collect(),
                 lints: defaults.lints,
                 fetch: defaults.fetch,
+                preamble: defaults.preamble,
             },
         )
     }
@@ -193,6 +213,7 @@ impl<'a, R> Linter<'a, R> {
                     .map(|(s, l)| (s.into(), Override::enable(l)))
                     .collect(),
                 fetch: Default::default(),
+                preamble: None,
             },
         )
     }
@@ -257,6 +278,19 @@ impl<'a, R> Linter<'a, R> {
         self.fetch = Box::new(fetch);
         self
     }
+
+    /// Measure how long each lint, and fetching and parsing, take.
+    pub fn record_timings(mut self, timings: &'a Timings) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
+    /// Reuse diagnostics from `cache` for files that haven't changed, and
+    /// store the diagnostics of those that have.
+    pub fn use_cache(mut self, cache: &'a Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
 }
 
 impl<'a, R> Linter<'a, R>
@@ -282,37 +316,60 @@ where
             panic!("no sources given");
         }
 
+        let mut prepared = Vec::with_capacity(self.lints.len());
+        for (slug, (annotation_level, lint)) in &self.lints {
+            let stopwatch = Stopwatch::start(self.timings);
+            let compiled = lint.prepare();
+            stopwatch.prepare(slug);
+            let compiled = compiled.context(LintSnafu { origin: None })?;
+            prepared.push((slug.as_str(), *annotation_level, lint, compiled));
+        }
+
+        let mut lints: Vec<_> = prepared
+            .iter()
+            .map(|(slug, level, lint, compiled)| (*slug, *level, compiled.as_ref().unwrap_or(lint)))
+            .collect();
+        lints.sort_by_key(|l| l.0);
+
         let mut to_check = Vec::with_capacity(self.sources.len());
+        let mut depends_on = Vec::with_capacity(self.sources.len());
         let mut fetched_eips = HashMap::new();
 
         for source in self.sources {
             let source_origin = source.origin().map(Path::to_path_buf);
+            let stopwatch = Stopwatch::start(self.timings);
             let source_content = source.fetch(&*self.fetch).await?;
+            stopwatch.fetch();
 
             to_check.push((source_origin, source_content));
+            depends_on.push(BTreeSet::new());
 
             let (source_origin, source_content) = to_check.last().unwrap();
             let display_origin = source_origin.as_deref().map(Path::to_string_lossy);
             let display_origin = display_origin.as_deref();
 
             let arena = Arena::new();
-            let inner = match process(&reporters::Null, &arena, display_origin, source_content)? {
+            let stopwatch = Stopwatch::start(self.timings);
+            let processed = process(&reporters::Null, &arena, display_origin, source_content)?;
+            stopwatch.parse();
+            let inner = match processed {
                 Some(i) => i,
                 None => continue,
             };
 
-            for (slug, lint) in &self.lints {
+            for (slug, _, lint) in &lints {
                 let context = FetchContext {
                     body: inner.body,
                     preamble: &inner.preamble,
                     fetch_proposals: Default::default(),
                 };
 
-                lint.1
-                    .find_resources(&context)
-                    .with_context(|_| LintSnafu {
-                        origin: source_origin.clone(),
-                    })?;
+                let stopwatch = Stopwatch::start(self.timings);
+                let found = lint.find_resources(&context);
+                stopwatch.find_resources(slug);
+                found.with_context(|_| LintSnafu {
+                    origin: source_origin.clone(),
+                })?;
 
                 let fetch_proposals = context.fetch_proposals.into_inner();
 
@@ -323,7 +380,7 @@ where
                 ensure!(
                     fetch_proposals.is_empty() || !source.is_string(),
                     SliceFetchedSnafu {
-                        lint: slug,
+                        lint: *slug,
                         origin: source_origin.clone(),
                     }
                 );
@@ -332,6 +389,11 @@ where
                     continue;
                 }
 
+                depends_on
+                    .last_mut()
+                    .unwrap()
+                    .extend(fetch_proposals.iter().copied());
+
                 let source_path = match source {
                     Source::File(p) => p,
                     _ => unreachable!(),
@@ -350,6 +412,8 @@ where
                     let basename =
                         formatx!(&self.proposal_format, proposal).expect("bad proposal format");
 
+                    let stopwatch = Stopwatch::start(self.timings);
+
                     let mut plain_path = root.join(&basename);
                     plain_path.set_extension("md");
                     let plain = Source::File(&plain_path).fetch(&*self.fetch).await;
@@ -369,6 +433,8 @@ where
                         (Err(e), Err(_)) => Err(e),
                     };
 
+                    stopwatch.fetch();
+
                     entry.insert(content);
                 }
             }
@@ -386,55 +452,88 @@ where
                 }
             };
 
-            let inner = match process(&self.reporter, &resources_arena, None, source)? {
+            let stopwatch = Stopwatch::start(self.timings);
+            let processed = process(&self.reporter, &resources_arena, None, source)?;
+            stopwatch.parse();
+            let inner = match processed {
                 Some(s) => s,
                 None => return Ok(self.reporter),
             };
             parsed_eips.insert(*number, Ok(inner));
         }
 
-        let mut lints: Vec<_> = self.lints.iter().collect();
-        lints.sort_by_key(|l| l.0);
+        let recorder = self.cache.map(|_| Recorder::new(&self.reporter));
+        let reporter: &dyn Reporter = match recorder {
+            Some(ref r) => r,
+            None => &self.reporter,
+        };
 
-        for (origin, source) in &to_check {
+        for ((origin, source), dependencies) in to_check.iter().zip(&depends_on) {
             let display_origin = origin.as_ref().map(|p| p.to_string_lossy().into_owned());
             let display_origin = display_origin.as_deref();
 
+            let cache = self.cache.map(|cache| {
+                let dependencies = dependencies
+                    .iter()
+                    .map(|n| (*n, fetched_eips[n].as_deref().ok()));
+                (cache, cache.key(display_origin, source, dependencies))
+            });
+
+            if let Some(messages) = cache.as_ref().and_then(|(c, k)| c.get(k)) {
+                for message in messages {
+                    self.reporter
+                        .report(message)
+                        .map_err(LintError::from)
+                        .with_context(|_| LintSnafu {
+                            origin: origin.clone(),
+                        })?;
+                }
+                continue;
+            }
+
             let arena = Arena::new();
-            let inner = match process(&self.reporter, &arena, display_origin, source)? {
-                Some(i) => i,
-                None => continue,
-            };
+            let stopwatch = Stopwatch::start(self.timings);
+            let processed = process(reporter, &arena, display_origin, source)?;
+            stopwatch.parse();
+
+            if let Some(inner) = processed {
+                let mut settings = LintSettings {
+                    _p: std::marker::PhantomData,
+                    default_annotation_level: Level::Error,
+                };
 
-            let mut settings = LintSettings {
-                _p: std::marker::PhantomData,
-                default_annotation_level: Level::Error,
-            };
+                for modifier in &self.modifiers {
+                    let context = Context {
+                        inner: inner.clone(),
+                        reporter,
+                        eips: &parsed_eips,
+                        annotation_level: settings.default_annotation_level,
+                    };
 
-            for modifier in &self.modifiers {
-                let context = Context {
-                    inner: inner.clone(),
-                    reporter: &self.reporter,
-                    eips: &parsed_eips,
-                    annotation_level: settings.default_annotation_level,
-                };
+                    modifier.modify(&context, &mut settings)?;
+                }
 
-                modifier.modify(&context, &mut settings)?;
-            }
+                for (slug, annotation_level, lint) in &lints {
+                    let annotation_level =
+                        annotation_level.unwrap_or(settings.default_annotation_level);
+                    let context = Context {
+                        inner: inner.clone(),
+                        reporter,
+                        eips: &parsed_eips,
+                        annotation_level,
+                    };
 
-            for (slug, (annotation_level, lint)) in &lints {
-                let annotation_level =
-                    annotation_level.unwrap_or(settings.default_annotation_level);
-                let context = Context {
-                    inner: inner.clone(),
-                    reporter: &self.reporter,
-                    eips: &parsed_eips,
-                    annotation_level,
-                };
+                    let stopwatch = Stopwatch::start(self.timings);
+                    let linted = lint.lint(slug, &context);
+                    stopwatch.lint(slug);
+                    linted.with_context(|_| LintSnafu {
+                        origin: origin.clone(),
+                    })?;
+                }
+            }
 
-                lint.lint(slug, &context).with_context(|_| LintSnafu {
-                    origin: origin.clone(),
-                })?;
+            if let (Some((cache, key)), Some(recorder)) = (cache, &recorder) {
+                cache.insert(key, recorder.take());
             }
         }
 
@@ -462,7 +561,7 @@ fn process<'a>(
 ) -> Result<Option<InnerContext<'a>>, Error> {
     let (preamble_source, body_source) = match Preamble::split(source) {
         Ok(v) => v,
-        Err(SplitError::MissingStart { .. }) | Err(SplitError::LeadingGarbage { .. }) => {
+        Err(SplitError::MissingStart) | Err(SplitError::LeadingGarbage) => {
             let mut footer = Vec::new();
             if source.as_bytes().get(3) == Some(&b'\r') {
                 footer.push(Level::Help.title(
@@ -487,7 +586,7 @@ fn process<'a>(
                 })?;
             return Ok(None);
         }
-        Err(SplitError::MissingEnd { .. }) => {
+        Err(SplitError::MissingEnd) => {
             reporter
                 .report(
                     Level::Error
@@ -574,6 +673,18 @@ impl LevelExt for Level {
     }
 }
 
+/// Remove and replace with str::floor_char_boundary if round_char_boundary stabilizes.
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    if index >= text.len() {
+        return text.len();
+    }
+
+    (0..=index)
+        .rev()
+        .find(|p| text.is_char_boundary(*p))
+        .unwrap()
+}
+
 /// Remove and replace with str::ceil_char_boundary if round_char_boundary stabilizes.
 fn ceil_char_boundary(text: &str, index: usize) -> usize {
     if index > text.len() {
@@ -10,7 +10,7 @@ pub mod preamble;
 
 use eipw_snippets::{Level, Message, Snippet};
 
-use comrak::nodes::{Ast, AstNode, LineColumn};
+use comrak::nodes::{Ast, AstNode, LineColumn, Sourcepos};
 
 use crate::reporters::{self, Reporter};
 use crate::{LevelExt, SnippetExt};
@@ -26,9 +26,22 @@ use snafu::Snafu;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::string::FromUtf8Error;
 
+/// Report a bug in source position handling. Fuzzers (built with
+/// `--cfg fuzzing`) and property tests (built with the `testing` feature)
+/// should treat these as crashes.
+macro_rules! known_bug {
+    ($($arg:tt)*) => {
+        if cfg!(any(fuzzing, feature = "testing")) {
+            panic!("KNOWN BUG: {}", format_args!($($arg)*));
+        } else {
+            eprintln!("KNOWN BUG: {}", format_args!($($arg)*));
+        }
+    };
+}
+
 #[derive(Debug, Snafu)]
 #[non_exhaustive]
 pub enum Error {
@@ -105,23 +118,61 @@ where
 
     fn line_column_index(&self, line_column: LineColumn) -> usize {
         let line_index = self.line_index(line_column.line);
-        line_index + line_column.column - 1
+        (line_index + line_column.column)
+            .saturating_sub(1)
+            .min(self.inner.source.len())
+    }
+
+    /// Nodes without a known end (like HTML blocks and empty table cells) have
+    /// an end column of zero. HTML blocks at the very end of the source
+    /// instead end where the previous block did, before they start.
+    fn unknown_end(ast: &Ast) -> bool {
+        let Sourcepos { start, end } = ast.sourcepos;
+        end.column == 0 || (end.line, end.column) < (start.line, start.column)
+    }
+
+    /// Byte range of `ast` in the source, widened to character boundaries.
+    fn ast_range(&self, ast: &Ast) -> Range<usize> {
+        let src = self.inner.source;
+        let start = crate::floor_char_boundary(src, self.line_column_index(ast.sourcepos.start));
+        let end = self.line_column_index(ast.sourcepos.end) + 1;
+
+        // Extend nodes without a known end to the end of their first line.
+        let end = if Self::unknown_end(ast) {
+            src[start..].find('\n').map_or(src.len(), |idx| start + idx)
+        } else if end < start {
+            known_bug!(
+                "end index ({}) of {:?} less than start index ({})",
+                end,
+                ast.value,
+                start,
+            );
+            start
+        } else {
+            end
+        };
+
+        start..crate::ceil_char_boundary(src, end)
     }
 
     pub fn ast_source(&self, ast: &Ast) -> &'a str {
-        let start = self.line_column_index(ast.sourcepos.start);
-        let end = self.line_column_index(ast.sourcepos.end);
-        &self.inner.source[start..=end]
+        &self.inner.source[self.ast_range(ast)]
     }
 
     pub fn ast_lines(&self, ast: &Ast) -> &'a str {
         let line_start_index = self.line_index(ast.sourcepos.start.line);
-        let mut line_end_index = self.line_index(ast.sourcepos.end.line);
+        let mut line_end_index = if Self::unknown_end(ast) {
+            line_start_index
+        } else {
+            self.line_index(ast.sourcepos.end.line)
+        };
 
         if line_end_index < line_start_index {
-            eprintln!(
-                "KNOWN BUG: end index ({}) of {:?} less than start index ({})",
-                line_end_index, ast.value, line_start_index,
+            known_bug!(
+                "end index ({}) of {:?} less than start index ({})",
+                line_end_index,
+                ast.value,
+                line_start_index,
             );
             line_end_index = line_start_index;
         }
@@ -146,24 +197,18 @@ where
         let line_start_index = self.line_index(ast.sourcepos.start.line);
         let level = level.into().unwrap_or(self.annotation_level());
 
-        let start_index = self.line_column_index(ast.sourcepos.start) - line_start_index;
-        let end_index = match self.line_column_index(ast.sourcepos.end) {
-            x if x >= line_start_index => x - line_start_index,
-            y => {
-                eprintln!(
-                    "KNOWN BUG: end index ({}) of {:?} less than start index ({})",
-                    y, ast.value, line_start_index,
-                );
-                let mut fake_end = start_index + 1;
-                if fake_end >= self.inner.source.len() {
-                    fake_end = self.inner.source.len() - 1;
-                }
-                fake_end
-            }
-        };
-
         let source = self.ast_lines(ast);
-        let annotation = level.span_utf8(source, start_index, end_index + 1 - start_index);
+        let range = self.ast_range(ast);
+        let start_index = range
+            .start
+            .saturating_sub(line_start_index)
+            .min(source.len());
+        let end_index = range
+            .end
+            .saturating_sub(line_start_index)
+            .clamp(start_index, source.len());
+
+        let annotation = level.span_utf8(source, start_index, end_index - start_index);
 
         let annotation = match label.into() {
             None => annotation,
@@ -236,6 +281,15 @@ impl<'a> FetchContext<'a> {
 }
 
 pub trait Lint: Debug {
+    /// Do the work that doesn't depend on any particular file, like compiling
+    /// patterns, once per run.
+    ///
+    /// Returns the lint to use in place of this one for the rest of the run,
+    /// or `None` to keep using this one.
+    fn prepare(&self) -> Result<Option<Box<dyn Lint>>, Error> {
+        Ok(None)
+    }
+
     fn find_resources(&self, _ctx: &FetchContext<'_>) -> Result<(), Error> {
         Ok(())
     }
@@ -243,7 +297,38 @@ pub trait Lint: Debug {
     fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error>;
 }
 
+/// Lints that do the work that doesn't depend on any particular file, like
+/// compiling patterns, by building another lint to run in their place.
+///
+/// [`Lint::prepare`] compiles these once per run. Used without preparing,
+/// they're compiled again for every file.
+pub trait Compile: Debug {
+    fn compile(&self) -> Result<Box<dyn Lint>, Error>;
+}
+
+impl<T> Lint for T
+where
+    T: Compile,
+{
+    fn prepare(&self) -> Result<Option<Box<dyn Lint>>, Error> {
+        self.compile().map(Some)
+    }
+
+    fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
+        self.compile()?.find_resources(ctx)
+    }
+
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        self.compile()?.lint(slug, ctx)
+    }
+}
+
 impl Lint for Box<dyn Lint> {
+    fn prepare(&self) -> Result<Option<Box<dyn Lint>>, Error> {
+        let lint: &dyn Lint = self.deref();
+        lint.prepare()
+    }
+
     fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
         let lint: &dyn Lint = self.deref();
         lint.find_resources(ctx)
@@ -282,8 +367,7 @@ mod tests {
                 NodeEdge::Start(Node { data, .. }) => Some(data.borrow().clone()),
                 _ => None,
             })
-            .filter(pred)
-            .next()
+            .find(pred)
             .unwrap();
 
         context.ast_source(&link).to_owned()
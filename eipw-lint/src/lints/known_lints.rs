@@ -20,6 +20,7 @@ pub enum DefaultLint<S> {
     PreambleAuthor {
         name: preamble::Author<S>,
     },
+    PreambleCondition(preamble::Condition<S>),
     PreambleDate {
         name: preamble::Date<S>,
     },
@@ -41,6 +42,7 @@ pub enum DefaultLint<S> {
     },
     PreambleRequiredIfEq(preamble::RequiredIfEq<S>),
     PreambleRequiresStatus(preamble::RequiresStatus<S>),
+    PreambleTimeline(preamble::Timeline<S>),
     PreambleTrim(preamble::Trim),
     PreambleUint {
         name: preamble::Uint<S>,
@@ -83,6 +85,7 @@ where
     pub(crate) fn as_inner(&self) -> &dyn Lint {
         match self {
             Self::PreambleAuthor { name } => name,
+            Self::PreambleCondition(l) => l,
             Self::PreambleDate { name } => name,
             Self::PreambleFileName(l) => l,
             Self::PreambleLength(l) => l,
@@ -96,6 +99,7 @@ where
             Self::PreambleRequired { names } => names,
             Self::PreambleRequiredIfEq(l) => l,
             Self::PreambleRequiresStatus(l) => l,
+            Self::PreambleTimeline(l) => l,
             Self::PreambleTrim(l) => l,
             Self::PreambleUint { name } => name,
             Self::PreambleUintList { name } => name,
@@ -128,6 +132,11 @@ where
             Self::PreambleAuthor { name } => DefaultLint::PreambleAuthor {
                 name: preamble::Author(name.0.as_ref()),
             },
+            Self::PreambleCondition(l) => DefaultLint::PreambleCondition(preamble::Condition {
+                name: l.name.as_ref(),
+                rule: l.rule,
+                when: l.when.map_to_str(),
+            }),
             Self::PreambleDate { name } => DefaultLint::PreambleDate {
                 name: preamble::Date(name.0.as_ref()),
             },
@@ -191,6 +200,13 @@ where
                         .collect(),
                 })
             }
+            Self::PreambleTimeline(l) => DefaultLint::PreambleTimeline(preamble::Timeline {
+                created: l.created.as_ref(),
+                deadline: l.deadline.as_ref(),
+                status: l.status.as_ref(),
+                deadline_status: l.deadline_status.as_ref(),
+                today: l.today.as_ref().map(AsRef::as_ref),
+            }),
             Self::PreambleTrim(_) => DefaultLint::PreambleTrim(preamble::Trim),
             Self::PreambleUint { name } => DefaultLint::PreambleUint {
                 name: preamble::Uint(name.0.as_ref()),
@@ -270,6 +286,11 @@ impl<S> Lint for DefaultLint<S>
 where
     S: std::fmt::Debug + AsRef<str>,
 {
+    fn prepare(&self) -> Result<Option<Box<dyn Lint>>, super::Error> {
+        let lint = self.map_to_str();
+        lint.as_inner().prepare()
+    }
+
     fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), super::Error> {
         let lint = self.map_to_str();
         lint.as_inner().find_resources(ctx)
@@ -287,6 +308,13 @@ impl From<DefaultLint<&str>> for DefaultLint<String> {
             DefaultLint::PreambleAuthor { name } => DefaultLint::PreambleAuthor {
                 name: preamble::Author(name.0.to_string()),
             },
+            DefaultLint::PreambleCondition(l) => {
+                DefaultLint::PreambleCondition(preamble::Condition {
+                    name: l.name.to_string(),
+                    rule: l.rule,
+                    when: l.when.into(),
+                })
+            }
             DefaultLint::PreambleDate { name } => DefaultLint::PreambleDate {
                 name: preamble::Date(name.0.to_string()),
             },
@@ -350,6 +378,13 @@ impl From<DefaultLint<&str>> for DefaultLint<String> {
                         .collect(),
                 })
             }
+            DefaultLint::PreambleTimeline(l) => DefaultLint::PreambleTimeline(preamble::Timeline {
+                created: l.created.to_string(),
+                deadline: l.deadline.to_string(),
+                status: l.status.to_string(),
+                deadline_status: l.deadline_status.to_string(),
+                today: l.today.map(|x| x.to_string()),
+            }),
             DefaultLint::PreambleTrim(_) => DefaultLint::PreambleTrim(preamble::Trim),
             DefaultLint::PreambleUint { name } => DefaultLint::PreambleUint {
                 name: preamble::Uint(name.0.to_string()),
@@ -54,8 +54,11 @@ where
             };
 
             for node in fragment.tree.nodes() {
-                if !matches!(node.value(), HtmlNode::Comment(_)) {
-                    continue;
+                match node.value() {
+                    // Spelling directives are instructions for eipw, not prose.
+                    HtmlNode::Comment(c) if super::spell::directive(c).is_some() => continue,
+                    HtmlNode::Comment(_) => (),
+                    _ => continue,
                 }
 
                 slices.push(ctx.ast_snippet(&data, annotation_type, None));
@@ -9,7 +9,7 @@ use eipw_snippets::{Level, Snippet};
 use comrak::nodes::{Ast, NodeCodeBlock};
 use jsonschema::output::BasicOutput;
 
-use crate::lints::{Context, Error, Lint};
+use crate::lints::{Compile, Context, Error, Lint};
 use crate::tree::{self, Next, TraverseExt};
 use crate::SnippetExt;
 
@@ -30,11 +30,11 @@ pub struct JsonSchema<S> {
     pub help: S,
 }
 
-impl<S> Lint for JsonSchema<S>
+impl<S> Compile for JsonSchema<S>
 where
     S: Debug + Display + AsRef<str>,
 {
-    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
         let value: serde_json::Value =
             serde_json::from_str(self.schema.as_ref()).map_err(Error::custom)?;
 
@@ -54,12 +54,29 @@ where
             .map_err(|e| Whatever::without_source(e.to_string()))
             .map_err(Error::custom)?;
 
+        Ok(Box::new(Compiled {
+            language: self.language.as_ref().to_owned(),
+            schema,
+            help: self.help.as_ref().to_owned(),
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct Compiled {
+    language: String,
+    schema: Validator,
+    help: String,
+}
+
+impl Lint for Compiled {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
         let mut visitor = Visitor {
             ctx,
-            schema,
+            schema: &self.schema,
             slug,
-            language: self.language.as_ref(),
-            help: self.help.as_ref(),
+            language: &self.language,
+            help: &self.help,
         };
 
         ctx.body().traverse().visit(&mut visitor)?;
@@ -73,7 +90,7 @@ struct Visitor<'a, 'b, 'c> {
     language: &'c str,
     slug: &'c str,
     help: &'c str,
-    schema: Validator,
+    schema: &'c Validator,
 }
 
 impl<'a, 'b, 'c> tree::Visitor for Visitor<'a, 'b, 'c> {
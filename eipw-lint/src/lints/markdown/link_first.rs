@@ -11,7 +11,7 @@ use comrak::nodes::{
     NodeLink,
 };
 
-use crate::lints::{Context, Error, Lint};
+use crate::lints::{Compile, Context, Error, Lint};
 use crate::tree::{self, Next, TraverseExt};
 use crate::SnippetExt;
 
@@ -27,14 +27,21 @@ use std::fmt::{Debug, Display};
 #[serde(transparent)]
 pub struct LinkFirst<S>(pub S);
 
-impl<S> Lint for LinkFirst<S>
+impl<S> Compile for LinkFirst<S>
 where
     S: Display + Debug + AsRef<str>,
 {
-    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
-        let pattern = self.0.as_ref();
-        let re = Regex::new(pattern).map_err(Error::custom)?;
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
+        let re = Regex::new(self.0.as_ref()).map_err(Error::custom)?;
+        Ok(Box::new(Compiled(re)))
+    }
+}
 
+#[derive(Debug)]
+struct Compiled(Regex);
+
+impl Lint for Compiled {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
         let own_number = ctx
             .preamble()
             .by_name("eip")
@@ -44,8 +51,7 @@ where
 
         let mut visitor = Visitor {
             ctx,
-            re,
-            pattern,
+            re: &self.0,
             slug,
             own_number,
             linked: Default::default(),
@@ -60,8 +66,7 @@ where
 
 struct Visitor<'a, 'b, 'c> {
     ctx: &'c Context<'a, 'b>,
-    re: Regex,
-    pattern: &'c str,
+    re: &'c Regex,
     slug: &'c str,
     linked: HashSet<String>,
     link_depth: usize,
@@ -95,7 +100,7 @@ impl<'a, 'b, 'c> Visitor<'a, 'b, 'c> {
                 continue;
             }
 
-            let footer_label = format!("the pattern in question: `{}`", self.pattern);
+            let footer_label = format!("the pattern in question: `{}`", self.re.as_str());
 
             let annotations = match offset {
                 None => None,
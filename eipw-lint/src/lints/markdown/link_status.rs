@@ -8,7 +8,7 @@ use eipw_snippets::Level;
 
 use comrak::nodes::{Ast, AstNode, NodeValue};
 
-use crate::lints::{Context, Error, FetchContext, Lint};
+use crate::lints::{Compile, Context, Error, FetchContext, Lint};
 
 use regex::Regex;
 
@@ -25,26 +25,45 @@ pub struct LinkStatus<S> {
     pub pattern: S,
 }
 
-impl<S> LinkStatus<S>
+impl<S> Compile for LinkStatus<S>
 where
-    S: AsRef<str>,
+    S: Debug + Display + AsRef<str>,
 {
-    fn tier(&self, map: &HashMap<&str, usize>, ctx: &Context<'_, '_>) -> usize {
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
+        let mut tiers = HashMap::new();
+        for (tier, values) in self.flow.iter().enumerate() {
+            for value in values {
+                tiers.insert(value.as_ref().to_owned(), tier + 1);
+            }
+        }
+
+        Ok(Box::new(Compiled {
+            status: self.status.as_ref().to_owned(),
+            tiers,
+            re: Regex::new(self.pattern.as_ref()).map_err(Error::custom)?,
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct Compiled {
+    status: String,
+    tiers: HashMap<String, usize>,
+    re: Regex,
+}
+
+impl Compiled {
+    fn tier(&self, ctx: &Context<'_, '_>) -> usize {
         ctx.preamble()
-            .by_name(self.status.as_ref())
+            .by_name(&self.status)
             .map(|f| f.value())
             .map(str::trim)
-            .and_then(|s| map.get(s))
+            .and_then(|s| self.tiers.get(s))
             .copied()
             .unwrap_or(0)
     }
 
-    fn find_links<'a>(
-        &self,
-        node: &'a AstNode<'a>,
-    ) -> impl 'a + Iterator<Item = (Ast, u32, String)> {
-        let re = Regex::new(self.pattern.as_ref()).unwrap();
-
+    fn find_links<'a>(&self, node: &'a AstNode<'a>) -> Vec<(Ast, u32, String)> {
         node.descendants()
             // Find all URLs and the lines they appear on.
             .filter_map(|start| match &*start.data.borrow() {
@@ -55,7 +74,7 @@ where
                 _ => None,
             })
             .filter_map(move |(start_line, url)| {
-                re.captures(&url).map(|c| {
+                self.re.captures(&url).map(|c| {
                     (
                         start_line,
                         c.get(1)
@@ -67,15 +86,14 @@ where
                     )
                 })
             })
+            .collect()
     }
 }
 
-impl<S> Lint for LinkStatus<S>
-where
-    S: Debug + Display + AsRef<str>,
-{
+impl Lint for Compiled {
     fn find_resources(&self, ctx: &FetchContext<'_>) -> Result<(), Error> {
         self.find_links(ctx.body())
+            .into_iter()
             .map(|x| x.1)
             .collect::<HashSet<_>>()
             .into_iter()
@@ -85,14 +103,7 @@ where
     }
 
     fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
-        let mut map = HashMap::new();
-        for (tier, values) in self.flow.iter().enumerate() {
-            for value in values {
-                map.insert(value.as_ref(), tier + 1);
-            }
-        }
-
-        let my_tier = self.tier(&map, ctx);
+        let my_tier = self.tier(ctx);
         let mut min = usize::MAX;
 
         for (ast, number, whole) in self.find_links(ctx.body()) {
@@ -110,7 +121,7 @@ where
                 }
             };
 
-            let their_tier = self.tier(&map, &eip);
+            let their_tier = self.tier(&eip);
 
             if their_tier < min {
                 min = their_tier;
@@ -125,13 +136,14 @@ where
                 whole,
                 self.status,
                 ctx.preamble()
-                    .by_name(self.status.as_ref())
+                    .by_name(&self.status)
                     .map(|f| f.value())
                     .unwrap_or("<missing>")
                     .trim(),
             );
 
-            let mut choices = map
+            let mut choices = self
+                .tiers
                 .iter()
                 .filter_map(|(v, t)| if *t <= min { Some(v) } else { None })
                 .map(ToString::to_string)
@@ -8,7 +8,7 @@ use eipw_snippets::Level;
 
 use comrak::nodes::{Ast, NodeCode};
 
-use crate::lints::{Context, Error, Lint};
+use crate::lints::{Compile, Context, Error, Lint};
 use crate::tree::{self, Next, TraverseExt};
 
 use ::regex::Regex;
@@ -23,17 +23,24 @@ use std::fmt::{Debug, Display};
 #[serde(transparent)]
 pub struct NoBackticks<S>(pub S);
 
-impl<S> Lint for NoBackticks<S>
+impl<S> Compile for NoBackticks<S>
 where
     S: Display + Debug + AsRef<str>,
 {
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
+        let re = Regex::new(self.0.as_ref()).map_err(Error::custom)?;
+        Ok(Box::new(Compiled(re)))
+    }
+}
+
+#[derive(Debug)]
+struct Compiled(Regex);
+
+impl Lint for Compiled {
     fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
-        let pattern = self.0.as_ref();
-        let re = Regex::new(pattern).map_err(Error::custom)?;
         let mut visitor = Visitor {
             ctx,
-            re,
-            pattern,
+            re: &self.0,
             slug,
         };
         ctx.body().traverse().visit(&mut visitor)?;
@@ -43,8 +50,7 @@ where
 
 struct Visitor<'a, 'b, 'c> {
     ctx: &'c Context<'a, 'b>,
-    re: Regex,
-    pattern: &'c str,
+    re: &'c Regex,
     slug: &'c str,
 }
 
@@ -54,7 +60,7 @@ impl<'a, 'b, 'c> Visitor<'a, 'b, 'c> {
             return Ok(Next::TraverseChildren);
         }
 
-        let footer_label = format!("the pattern in question: `{}`", self.pattern);
+        let footer_label = format!("the pattern in question: `{}`", self.re.as_str());
         self.ctx.report(
             self.ctx
                 .annotation_level()
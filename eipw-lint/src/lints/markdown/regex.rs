@@ -11,7 +11,7 @@ use comrak::nodes::{
     NodeLink,
 };
 
-use crate::lints::{Context, Error, Lint};
+use crate::lints::{Compile, Context, Error, Lint};
 use crate::tree::{self, Next, TraverseExt};
 use crate::SnippetExt;
 
@@ -40,20 +40,33 @@ pub struct Regex<S> {
     pub message: S,
 }
 
-impl<S> Lint for Regex<S>
+impl<S> Compile for Regex<S>
 where
     S: Display + Debug + AsRef<str>,
 {
-    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
-        let pattern = self.pattern.as_ref();
-        let re = TextRegex::new(pattern).map_err(Error::custom)?;
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
+        Ok(Box::new(Compiled {
+            mode: self.mode,
+            re: TextRegex::new(self.pattern.as_ref()).map_err(Error::custom)?,
+            message: self.message.as_ref().to_owned(),
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct Compiled {
+    mode: Mode,
+    re: TextRegex,
+    message: String,
+}
 
+impl Lint for Compiled {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
         let mut visitor = match self.mode {
             Mode::Excludes => ExcludesVisitor {
                 ctx,
-                re,
-                message: self.message.as_ref(),
-                pattern,
+                re: &self.re,
+                message: &self.message,
                 slug,
             },
         };
@@ -66,8 +79,7 @@ where
 
 struct ExcludesVisitor<'a, 'b, 'c> {
     ctx: &'c Context<'a, 'b>,
-    re: TextRegex,
-    pattern: &'c str,
+    re: &'c TextRegex,
     slug: &'c str,
     message: &'c str,
 }
@@ -94,7 +106,7 @@ impl<'a, 'b, 'c> ExcludesVisitor<'a, 'b, 'c> {
                 .collect(),
         };
 
-        let footer_label = format!("the pattern in question: `{}`", self.pattern);
+        let footer_label = format!("the pattern in question: `{}`", self.re.as_str());
 
         self.ctx.report(
             self.ctx
@@ -4,11 +4,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use eipw_snippets::Level;
+use eipw_snippets::{Level, Suggestion};
 
 use comrak::nodes::Ast;
 
-use crate::lints::{Context, Error, Lint};
+use crate::lints::{Compile, Context, Error, Lint};
 use crate::tree::{self, Next, TraverseExt};
 
 use regex::{Regex, RegexSet};
@@ -29,16 +29,28 @@ pub struct RelativeLinks<S> {
 const RE_URL: &str =
     r"^(https?:)?//(?:eips|ercs)\.ethereum\.org/(?:EIPS|ERCS)/(?:eip|erc)-(\d+)|(assets/.+)$";
 
-impl<S> Lint for RelativeLinks<S>
+impl<S> Compile for RelativeLinks<S>
 where
     S: Debug + Display + AsRef<str>,
 {
-    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
-        let re = Regex::new("(^/)|(://)").unwrap();
-        let eip_re = Regex::new(RE_URL).unwrap();
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
+        Ok(Box::new(Compiled {
+            re: Regex::new("(^/)|(://)").unwrap(),
+            eip_re: Regex::new(RE_URL).unwrap(),
+            exceptions: RegexSet::new(&self.exceptions).map_err(Error::custom)?,
+        }))
+    }
+}
 
-        let exceptions = RegexSet::new(&self.exceptions).map_err(Error::custom)?;
+#[derive(Debug)]
+struct Compiled {
+    re: Regex,
+    eip_re: Regex,
+    exceptions: RegexSet,
+}
 
+impl Lint for Compiled {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
         let mut visitor = Visitor::default();
         ctx.body().traverse().visit(&mut visitor)?;
 
@@ -58,15 +70,15 @@ where
                 }
             };
 
-            if !re.is_match(&address) {
+            if !self.re.is_match(&address) {
                 continue;
             }
 
-            if exceptions.is_match(&address) {
+            if self.exceptions.is_match(&address) {
                 continue;
             }
 
-            let (suggestion, extra_help) = if let Some(caps) = eip_re.captures(&address) {
+            let (suggestion, extra_help) = if let Some(caps) = self.eip_re.captures(&address) {
                 if let Some(id_number) = caps.get(2) {
                     let suggestion = format!("./eip-{}.md", id_number.as_str());
                     (suggestion, true)
@@ -74,19 +86,33 @@ where
                     let suggestion = format!("../{}", asset_path.as_str());
                     (suggestion, true)
                 } else {
-                    (address, false)
+                    (address.clone(), false)
                 }
             } else if address.contains("//creativecommons.org/publicdomain/zero/1.0/") {
                 ("../LICENSE.md".to_string(), true)
             } else {
-                (address, false)
+                (address.clone(), false)
             };
 
             let mut footer = vec![];
+            let mut snippet = ctx.ast_snippet(&ast, None, "used here");
 
             let suggestion_label = format!("use `{}` instead", suggestion);
             if extra_help {
                 footer.push(Level::Help.title(&suggestion_label));
+
+                // Only suggest a fix when the address appears verbatim as the
+                // destination (and not, for example, escaped or as a reference.)
+                let node = snippet.annotations[0].range.clone();
+                let found = snippet
+                    .source
+                    .get(node.clone())
+                    .and_then(|n| destination(n, &address));
+                if let Some(start) = found {
+                    let start = node.start + start;
+                    let range = start..start + address.len();
+                    snippet = snippet.suggestion(Suggestion::new(range, suggestion));
+                }
             }
 
             ctx.report(
@@ -94,7 +120,7 @@ where
                     .title("non-relative link or image")
                     .id(slug)
                     .footers(footer)
-                    .snippet(ctx.ast_snippet(&ast, None, "used here")),
+                    .snippet(snippet),
             )?;
         }
 
@@ -102,6 +128,15 @@ where
     }
 }
 
+/// Byte offset of `address` where it's used as the destination in `node`,
+/// either of an inline link (`[text](address)`) or of an HTML attribute.
+fn destination(node: &str, address: &str) -> Option<usize> {
+    ["](", "](<", "=\"", "='"].into_iter().find_map(|before| {
+        let needle = format!("{before}{address}");
+        node.find(&needle).map(|i| i + before.len())
+    })
+}
+
 #[derive(Debug)]
 enum OfInterest {
     Unsupported { what: &'static str, ast: Ast },
@@ -4,20 +4,19 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use eipw_snippets::{Level, Snippet};
+mod dictionary;
 
-use comrak::{
-    arena_tree::Node,
-    nodes::{Ast, AstNode, NodeValue},
-    Arena,
-};
-use html2text::render::TrivialDecorator;
-use lru::LruCache;
-use regex::{Regex, RegexSet};
-use zspell::Dictionary;
+use eipw_snippets::{Level, Snippet, Suggestion};
+
+use comrak::nodes::{Ast, AstNode, NodeValue};
+use regex::RegexSet;
+use scraper::node::Node as HtmlNode;
+use scraper::Html;
+
+use self::dictionary::{Speller, WordList};
 
 use crate::{
-    lints::{Context, Error, Lint},
+    lints::{Compile, Context, Error, Lint},
     tree::{Next, TraverseExt, Visitor},
     SnippetExt,
 };
@@ -25,29 +24,23 @@ use crate::{
 use serde::{Deserialize, Serialize};
 
 use std::{
-    cell::RefCell,
-    collections::HashSet,
+    collections::HashMap,
     fmt::{Debug, Display},
-    num::NonZeroUsize,
-    sync::{Arc, Mutex},
+    ops::Range,
+    sync::Arc,
 };
 
-const AFF: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/dictionaries/dictionaries/en/index.aff"
-));
-const DICT: &str = concat!(
-    include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/dictionaries/dictionaries/en/index.dic",
-    )),
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/custom.dic"))
-);
+/// Prefix of HTML comments listing extra words to accept in a single file, like
+/// `<!-- eipw-spell: wordA wordB -->`.
+const DIRECTIVE: &str = "eipw-spell:";
 
-lazy_static::lazy_static! {
-    static ref CACHE: Mutex<LruCache<String, Arc<Dictionary>>> =
-        LruCache::new(NonZeroUsize::MIN).into();
+/// The words listed in `comment` (the text between `<!--` and `-->`), if it's
+/// a `markdown-spell` directive.
+pub(crate) fn directive(comment: &str) -> Option<&str> {
+    comment.trim_start().strip_prefix(DIRECTIVE)
+}
 
+lazy_static::lazy_static! {
     static ref ALLOW: RegexSet = RegexSet::new([
         "^[-+~]?(0x)?[[:xdigit:]]+$",
         "^[[:punct:]]+$",
@@ -60,217 +53,244 @@ pub struct Spell<S> {
     pub personal_dictionary: S,
 }
 
-impl<S> Lint for Spell<S>
+impl<S> Compile for Spell<S>
 where
     S: Display + Debug + AsRef<str> + for<'eq> PartialEq<&'eq str>,
 {
-    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
-        let dict: Arc<Dictionary> = {
-            CACHE
-                .lock()
-                .unwrap()
-                .get_or_insert_ref(self.personal_dictionary.as_ref(), || {
-                    zspell::builder()
-                        .config_str(AFF)
-                        .dict_str(DICT)
-                        .personal_str(self.personal_dictionary.as_ref())
-                        .build()
-                        .expect("failed to build dictionary")
-                        .into()
-                })
-                .clone()
-        };
-
-        let arena = Arena::new();
-        let mut visitor = RemoveCode::new(&arena);
-        ctx.body().traverse().visit(&mut visitor).unwrap();
-
-        let mut output = Vec::new();
-        let options = crate::comrak_options();
-
-        comrak::format_html(visitor.current, &options, &mut output).unwrap();
-        let text =
-            html2text::from_read_with_decorator(output.as_slice(), usize::MAX, TrivialDecorator {})
-                .unwrap();
-
-        let mut mistakes: Vec<_> = dict
-            .check_indices(&text)
-            // Cannot use the index from `check_indices` because we spellcheck against the
-            // plaintext rendered output, so the source position won't match.
-            .map(|(_, w)| w)
-            .filter(|w| !ALLOW.is_match(w))
-            .collect::<HashSet<&str>>()
-            .into_iter()
-            .map(Mistake::new)
-            .collect();
-
-        let mut visitor = TextFind {
-            slug,
-            ctx,
-            mistakes: &mut mistakes,
-        };
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
+        let dictionary = dictionary::dictionary(self.personal_dictionary.as_ref())?;
+        Ok(Box::new(Compiled {
+            speller: Speller::new(dictionary),
+        }))
+    }
+}
 
-        ctx.body().traverse().visit(&mut visitor)?;
+#[derive(Debug)]
+struct Compiled {
+    speller: Speller,
+}
 
-        for Mistake {
-            misspelling,
-            reported,
-            ..
-        } in visitor.mistakes.iter_mut()
-        {
-            if *reported {
-                continue;
+impl Compiled {
+    /// Words accepted by `eipw-spell` directives anywhere in the body.
+    fn inline_words(ctx: &Context<'_, '_>) -> WordList {
+        let mut words = WordList::default();
+
+        for node in ctx.body().descendants() {
+            let data = node.data.borrow();
+            let fragment = match data.value {
+                NodeValue::HtmlBlock(ref b) => Html::parse_fragment(&b.literal),
+                NodeValue::HtmlInline(ref s) => Html::parse_fragment(s),
+                _ => continue,
+            };
+
+            for node in fragment.tree.nodes() {
+                if let HtmlNode::Comment(comment) = node.value() {
+                    if let Some(listed) = directive(comment) {
+                        words.extend(listed.split_whitespace());
+                    }
+                }
             }
-
-            let label = format!("the word `{misspelling}` is misspelled");
-            ctx.report(
-                ctx.annotation_level()
-                    .title(&label)
-                    .id(slug)
-                    .snippet(ctx.ast_snippet(
-                        &ctx.body().data.borrow(),
-                        ctx.annotation_level(),
-                        "somewhere here",
-                    ))
-                    .footer(Level::Warning.title("could not find a line number for this message")),
-            )?;
         }
 
-        Ok(())
+        words
     }
 }
 
-struct RemoveCode<'a> {
-    arena: &'a Arena<AstNode<'a>>,
-    current: &'a AstNode<'a>,
-}
+impl Lint for Compiled {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let speller = self.speller.clone().with(Arc::new(Self::inline_words(ctx)));
 
-impl<'a> RemoveCode<'a> {
-    fn new(arena: &'a Arena<AstNode<'a>>) -> Self {
-        let current: &'a AstNode<'a> = arena.alloc(Node::new(RefCell::new(Ast::new(
-            NodeValue::Document,
-            (1, 1).into(),
-        ))));
-        Self { arena, current }
-    }
-}
+        let mut extract = Extract::new(ctx);
+        ctx.body().traverse().visit(&mut extract).unwrap();
 
-impl<'a> Visitor for RemoveCode<'a> {
-    type Error = std::convert::Infallible;
+        let mut suggestions = HashMap::new();
 
-    fn enter(&mut self, node: &AstNode) -> Result<Next, Self::Error> {
-        match node.data.borrow().value {
-            NodeValue::Document => return Ok(Next::TraverseChildren),
-            NodeValue::Code(_) => return Ok(Next::SkipChildren),
-            NodeValue::CodeBlock(_) => return Ok(Next::SkipChildren),
-            _ => (),
-        };
+        for (index, word) in speller.check_indices(&extract.text) {
+            if ALLOW.is_match(word) {
+                continue;
+            }
 
-        let node = AstNode::from(node.data.clone().into_inner());
-        let node = self.arena.alloc(node);
-        self.current.append(node);
-        self.current = node;
-        Ok(Next::TraverseChildren)
-    }
+            let suggestions: &Vec<String> = suggestions
+                .entry(word)
+                .or_insert_with(|| speller.suggest(word, SUGGESTIONS));
 
-    fn depart(&mut self, node: &AstNode) -> Result<(), Self::Error> {
-        match node.data.borrow().value {
-            NodeValue::Document => return Ok(()),
-            NodeValue::Code(_) => return Ok(()),
-            NodeValue::CodeBlock(_) => return Ok(()),
-            _ => (),
-        };
+            let range = extract.source_range(index..index + word.len(), word);
+            report(slug, ctx, word, range, suggestions)?;
+        }
 
-        self.current = self.current.parent().unwrap();
         Ok(())
     }
 }
 
-#[derive(Debug)]
-struct Mistake<'a> {
-    misspelling: &'a str,
-    reported: bool,
-    pattern: Regex,
+/// How many suggestions to offer for each misspelled word.
+const SUGGESTIONS: usize = 3;
+
+fn report(
+    slug: &str,
+    ctx: &Context<'_, '_>,
+    misspelling: &str,
+    range: Range<usize>,
+    suggestions: &[String],
+) -> Result<(), Error> {
+    let source = ctx.inner.source;
+    let line_start = source[..range.start].rfind('\n').map_or(0, |idx| idx + 1);
+    let line_end = source[range.end..]
+        .find('\n')
+        .map_or(source.len(), |idx| range.end + idx);
+    let line = source[..line_start].matches('\n').count() + 1;
+    let span = range.start - line_start..range.end - line_start;
+
+    let mut snippet = Snippet::source(&source[line_start..line_end])
+        .origin_opt(ctx.origin())
+        .line_start(line)
+        .annotation(
+            ctx.annotation_level()
+                .span(span.clone())
+                .label("incorrectly spelled"),
+        );
+
+    let help = match suggestions {
+        [] => None,
+        [only] => {
+            // Only offer a fix when the span covers nothing but the word.
+            if source.get(range) == Some(misspelling) {
+                snippet = snippet.suggestion(Suggestion::new(span, only.clone()));
+            }
+            Some(format!("did you mean `{only}`?"))
+        }
+        many => Some(format!("did you mean one of: `{}`?", many.join("`, `"))),
+    };
+
+    let label = format!("the word `{misspelling}` is misspelled");
+    ctx.report(
+        ctx.annotation_level()
+            .title(&label)
+            .id(slug)
+            .footers(help.as_deref().map(|h| Level::Help.title(h)))
+            .snippet(snippet),
+    )
 }
 
-impl<'a> Mistake<'a> {
-    fn new(misspelling: &'a str) -> Self {
-        let escaped = regex::escape(misspelling);
-        let pattern = Regex::new(&format!(r"\b{escaped}\b")).unwrap();
-        Self {
-            misspelling,
-            reported: false,
-            pattern,
-        }
-    }
+/// A piece of [`Extract::text`] copied from a single node.
+#[derive(Debug)]
+struct Segment {
+    text: Range<usize>,
+    source: Range<usize>,
+
+    /// Whether `text` is identical to `source`. Escapes and entities, for
+    /// example, make them differ.
+    exact: bool,
 }
 
-struct TextFind<'a, 'b, 'c> {
-    slug: &'c str,
-    mistakes: &'c mut [Mistake<'c>],
+/// The prose of a proposal, with a map back to where each piece of it came
+/// from in the source.
+///
+/// Text inside inline formatting and links is joined together, while code,
+/// HTML, and the boundaries between blocks separate words.
+struct Extract<'a, 'b, 'c> {
     ctx: &'c Context<'a, 'b>,
+    text: String,
+    segments: Vec<Segment>,
 }
 
-impl<'a, 'b, 'c> TextFind<'a, 'b, 'c> {
-    fn check(
-        &self,
-        ast: &Ast,
-        haystack: &str,
-        mistake: &Mistake,
-    ) -> Result<bool, <Self as Visitor>::Error> {
-        let node_offset = match mistake.pattern.find(haystack) {
-            Some(o) => o.start(),
-            None => return Ok(false),
-        };
+impl<'a, 'b, 'c> Extract<'a, 'b, 'c> {
+    fn new(ctx: &'c Context<'a, 'b>) -> Self {
+        Self {
+            ctx,
+            text: String::new(),
+            segments: Vec::new(),
+        }
+    }
 
-        let source = self.ctx.ast_lines(ast);
+    fn separate(&mut self) {
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+    }
 
-        let line_offset = match source.find(haystack) {
-            Some(l) => l,
-            None => {
-                // This would be really weird. Means the source we want to use doesn't contain the
-                // text of the node we're checking. Instead of panicking, we'll just not mark this
-                // mistake as reported and let the fallback handle it.
-                return Ok(false);
+    fn push(&mut self, ast: &Ast, literal: &str) {
+        let source = self.ctx.ast_range(ast);
+        let exact = self.ctx.inner.source.get(source.clone()) == Some(literal);
+
+        let start = self.text.len();
+        self.text.push_str(literal);
+        self.segments.push(Segment {
+            text: start..self.text.len(),
+            source,
+            exact,
+        });
+    }
+
+    /// Byte range in the source of `word`, found at `range` in `self.text`.
+    fn source_range(&self, range: Range<usize>, word: &str) -> Range<usize> {
+        let first = &self.segments[self.segments.partition_point(|s| s.text.end <= range.start)];
+        let last = &self.segments[self.segments.partition_point(|s| s.text.end < range.end)];
+
+        if std::ptr::eq(first, last) && !first.exact {
+            // Look for the same occurrence of the word in the source instead.
+            let nth = self.text[first.text.start..range.start]
+                .matches(word)
+                .count();
+            let found = self.ctx.inner.source[first.source.clone()]
+                .match_indices(word)
+                .nth(nth);
+            if let Some((idx, _)) = found {
+                let start = first.source.start + idx;
+                return start..start + word.len();
             }
+        }
+
+        let start = match first.exact {
+            true => first.source.start + (range.start - first.text.start),
+            false => first.source.start,
         };
 
-        let start = node_offset + line_offset;
-        let end = start + mistake.misspelling.len();
-
-        let label = format!("the word `{}` is misspelled", mistake.misspelling);
-        self.ctx.report(
-            self.ctx
-                .annotation_level()
-                .title(&label)
-                .id(self.slug)
-                .snippet(
-                    Snippet::source(source)
-                        .origin_opt(self.ctx.origin())
-                        .line_start(ast.sourcepos.start.line)
-                        .annotation(
-                            self.ctx
-                                .annotation_level()
-                                .span(start..end)
-                                .label("incorrectly spelled"),
-                        ),
-                ),
-        )?;
-
-        Ok(true)
+        let end = match last.exact {
+            true => last.source.start + (range.end - last.text.start),
+            false => last.source.end,
+        };
+
+        start..end.max(start)
     }
 }
 
-impl<'a, 'b, 'c> Visitor for TextFind<'a, 'b, 'c> {
-    type Error = Error;
+impl<'a, 'b, 'c> Visitor for Extract<'a, 'b, 'c> {
+    type Error = std::convert::Infallible;
 
-    fn enter_text(&mut self, ast: &Ast, txt: &str) -> Result<Next, Self::Error> {
-        for ii in 0..self.mistakes.len() {
-            if self.check(ast, txt, &self.mistakes[ii])? {
-                self.mistakes[ii].reported = true;
+    fn enter(&mut self, node: &AstNode) -> Result<Next, Self::Error> {
+        let data = node.data.borrow();
+        match data.value {
+            NodeValue::Document => Ok(Next::TraverseChildren),
+            NodeValue::Text(ref txt) => {
+                self.push(&data, txt);
+                Ok(Next::TraverseChildren)
+            }
+            NodeValue::Emph
+            | NodeValue::Strong
+            | NodeValue::Strikethrough
+            | NodeValue::Superscript
+            | NodeValue::Subscript
+            | NodeValue::Underline
+            | NodeValue::SpoileredText
+            | NodeValue::Escaped
+            | NodeValue::Link(_)
+            | NodeValue::Image(_)
+            | NodeValue::WikiLink(_) => Ok(Next::TraverseChildren),
+            NodeValue::Code(_)
+            | NodeValue::CodeBlock(_)
+            | NodeValue::HtmlBlock(_)
+            | NodeValue::HtmlInline(_)
+            | NodeValue::FootnoteReference(_)
+            | NodeValue::Math(_)
+            | NodeValue::Raw(_)
+            | NodeValue::FrontMatter(_) => {
+                self.separate();
+                Ok(Next::SkipChildren)
+            }
+            _ => {
+                self.separate();
+                Ok(Next::TraverseChildren)
             }
         }
-
-        Ok(Next::TraverseChildren)
     }
 }
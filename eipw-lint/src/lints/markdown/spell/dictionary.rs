@@ -0,0 +1,261 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Spell-check dictionaries shared between lints and files.
+//!
+//! Building a Hunspell dictionary is expensive, so the base dictionary
+//! combined with each personal dictionary is built once and kept in a cache
+//! large enough to hold every dictionary a configuration is likely to use.
+//! Word lists that change from one file to the next are layered on top with a
+//! [`Speller`], without rebuilding anything.
+
+use lru::LruCache;
+
+use crate::lints::Error;
+
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+const AFF: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/dictionaries/dictionaries/en/index.aff"
+));
+const DICT: &str = concat!(
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/dictionaries/dictionaries/en/index.dic",
+    )),
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/custom.dic"))
+);
+
+/// How many differently configured dictionaries to keep built at once.
+const CAPACITY: NonZeroUsize = match NonZeroUsize::new(16) {
+    Some(c) => c,
+    None => unreachable!(),
+};
+
+/// Hunspell flag marking words that are correct, but never suggested.
+const NOSUGGEST: char = '!';
+
+lazy_static::lazy_static! {
+    static ref CACHE: Mutex<LruCache<String, Arc<Dictionary>>> =
+        LruCache::new(CAPACITY).into();
+}
+
+/// A built dictionary, and what's needed to suggest corrections from it.
+#[derive(Debug)]
+pub(crate) struct Dictionary {
+    words: zspell::Dictionary,
+
+    /// The entries flagged `NOSUGGEST`, with their affixes.
+    nosuggest: zspell::Dictionary,
+
+    /// Lowercase letters to insert or substitute when looking for
+    /// corrections, from the affix file's `TRY` line and the personal
+    /// dictionary.
+    letters: Vec<char>,
+}
+
+impl Dictionary {
+    fn build(personal: &str) -> Result<Self, Error> {
+        let words = zspell::builder()
+            .config_str(AFF)
+            .dict_str(DICT)
+            .personal_str(personal)
+            .build()
+            .map_err(Error::custom)?;
+
+        let nosuggest: Vec<&str> = DICT
+            .lines()
+            .filter(|l| {
+                l.split_once('/')
+                    .is_some_and(|(_, f)| f.contains(NOSUGGEST))
+            })
+            .collect();
+
+        let nosuggest = zspell::builder()
+            .config_str(AFF)
+            .dict_str(&format!("{}\n{}", nosuggest.len(), nosuggest.join("\n")))
+            .build()
+            .map_err(Error::custom)?;
+
+        let try_line = AFF.lines().find_map(|l| l.strip_prefix("TRY "));
+
+        let mut letters: Vec<char> = Vec::new();
+        let candidates = try_line.unwrap_or_default().chars().chain(personal.chars());
+        for c in candidates.flat_map(char::to_lowercase) {
+            if c.is_alphabetic() && !letters.contains(&c) {
+                letters.push(c);
+            }
+        }
+
+        Ok(Self {
+            words,
+            nosuggest,
+            letters,
+        })
+    }
+
+    /// Whether `word` is correct and may be offered as a suggestion.
+    fn suggestible(&self, word: &str) -> bool {
+        self.words.check_word(word) && !self.nosuggest.check_word(word)
+    }
+}
+
+/// Get the base dictionary extended with `personal`, building it only if it
+/// isn't already cached.
+pub(crate) fn dictionary(personal: &str) -> Result<Arc<Dictionary>, Error> {
+    let mut cache = CACHE.lock().unwrap();
+    let dict =
+        cache.try_get_or_insert_ref(personal, || Dictionary::build(personal).map(Arc::new))?;
+    Ok(dict.clone())
+}
+
+/// Additional correctly spelled words, without any affix rules.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WordList {
+    words: HashSet<String>,
+}
+
+impl WordList {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.words.contains(word) || self.words.contains(&word.to_lowercase())
+    }
+}
+
+impl<'a> Extend<&'a str> for WordList {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.words.extend(iter.into_iter().map(str::to_owned));
+    }
+}
+
+/// A built dictionary, plus any word lists layered on top of it.
+#[derive(Debug, Clone)]
+pub(crate) struct Speller {
+    dictionary: Arc<Dictionary>,
+    words: Vec<Arc<WordList>>,
+}
+
+impl Speller {
+    pub(crate) fn new(dictionary: Arc<Dictionary>) -> Self {
+        Self {
+            dictionary,
+            words: Vec::new(),
+        }
+    }
+
+    /// Also accept the words in `words`.
+    pub(crate) fn with(mut self, words: Arc<WordList>) -> Self {
+        if !words.is_empty() {
+            self.words.push(words);
+        }
+        self
+    }
+
+    /// Up to `limit` words from the dictionary within one edit of `word`.
+    ///
+    /// An edit inserts, removes, or replaces one letter, or swaps two adjacent
+    /// ones. Every such variant of `word` is looked up in the dictionary, so
+    /// this is only as thorough as that: unlike Hunspell, it doesn't try
+    /// phonetic or multi-letter replacements.
+    ///
+    /// Swapped letters are the most common typo, so those suggestions come
+    /// first. After that, typos rarely change the first letter of a word, so
+    /// suggestions that keep it come next, and are otherwise in alphabetical
+    /// order.
+    /// Capitalized words are edited as if they were lowercase, and their
+    /// suggestions capitalized to match.
+    pub(crate) fn suggest(&self, word: &str, limit: usize) -> Vec<String> {
+        let mut chars = word.chars();
+        let first = chars.next();
+        let capitalized = first.is_some_and(char::is_uppercase);
+
+        let lower: Vec<char> = match first {
+            Some(f) if capitalized => f.to_lowercase().chain(chars).collect(),
+            _ => word.chars().collect(),
+        };
+
+        let mut suggestions: Vec<(Edit, String)> = edits(&lower, &self.dictionary.letters)
+            .map(|(e, c)| (e, if capitalized { capitalize(&c) } else { c }))
+            .filter(|(_, c)| c != word && self.dictionary.suggestible(c))
+            .collect();
+
+        suggestions.sort_unstable_by(|(a_edit, a), (b_edit, b)| {
+            let a_first = a.chars().next() != first;
+            let b_first = b.chars().next() != first;
+            a_edit
+                .cmp(b_edit)
+                .then_with(|| a_first.cmp(&b_first))
+                .then_with(|| a.cmp(b))
+        });
+
+        let mut seen = HashSet::new();
+        suggestions
+            .into_iter()
+            .map(|(_, c)| c)
+            .filter(|c| seen.insert(c.clone()))
+            .take(limit)
+            .collect()
+    }
+
+    /// Byte offsets and text of the misspelled words in `text`.
+    pub(crate) fn check_indices<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+        self.dictionary
+            .words
+            .check_indices(text)
+            .filter(|(_, w)| !self.words.iter().any(|l| l.contains(w)))
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .into_iter()
+        .flat_map(char::to_uppercase)
+        .chain(chars)
+        .collect()
+}
+
+/// Kinds of edit, from the most to the least likely typo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Edit {
+    Swap,
+    Single,
+}
+
+/// Every string one edit away from `word`, possibly with repeats.
+fn edits<'a>(word: &'a [char], letters: &'a [char]) -> impl Iterator<Item = (Edit, String)> + 'a {
+    let splice = move |at: usize, remove: usize, insert: &[char]| -> String {
+        word[..at]
+            .iter()
+            .chain(insert)
+            .chain(&word[at + remove..])
+            .collect()
+    };
+
+    let removed = (0..word.len()).map(move |i| splice(i, 1, &[]));
+    let swapped = (1..word.len()).map(move |i| splice(i - 1, 2, &[word[i], word[i - 1]]));
+    let replaced =
+        (0..word.len()).flat_map(move |i| letters.iter().map(move |l| splice(i, 1, &[*l])));
+    let inserted =
+        (0..=word.len()).flat_map(move |i| letters.iter().map(move |l| splice(i, 0, &[*l])));
+
+    swapped.map(|w| (Edit::Swap, w)).chain(
+        removed
+            .chain(replaced)
+            .chain(inserted)
+            .map(|w| (Edit::Single, w)),
+    )
+}
@@ -5,6 +5,7 @@
  */
 
 pub mod author;
+pub mod condition;
 pub mod date;
 pub mod file_name;
 pub mod length;
@@ -18,11 +19,13 @@ pub mod require_referenced;
 pub mod required;
 pub mod required_if_eq;
 pub mod requires_status;
+pub mod timeline;
 pub mod trim;
 pub mod uint;
 pub mod url;
 
 pub use self::author::Author;
+pub use self::condition::Condition;
 pub use self::date::Date;
 pub use self::file_name::FileName;
 pub use self::length::Length;
@@ -36,6 +39,7 @@ pub use self::require_referenced::RequireReferenced;
 pub use self::required::Required;
 pub use self::required_if_eq::RequiredIfEq;
 pub use self::requires_status::RequiresStatus;
+pub use self::timeline::Timeline;
 pub use self::trim::Trim;
 pub use self::uint::{Uint, UintList};
 pub use self::url::Url;
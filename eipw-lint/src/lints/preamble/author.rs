@@ -4,10 +4,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use eipw_preamble::author::{self, AuthorError};
 use eipw_snippets::{Level, Message, Snippet};
 
-use regex::RegexSet;
-
 use crate::{
     lints::{Context, Error, Lint},
     LevelExt, SnippetExt,
@@ -41,47 +40,44 @@ where
             Some(s) => s,
         };
 
-        // TODO: Email addresses are insane, and can probably contain commas,
-        //       parentheses, and greater-/less- than symbols. For correctness,
-        //       we should switch to a parser that can handle those cases.
-
-        let items = field.value().split(',');
-
-        let set = RegexSet::new([
-            // Match a GitHub username.
-            r"^[^()<>,@]+ \(@[a-zA-Z\d-]+\)$",
-            // Match an email address.
-            r"^[^()<>,@]+ <[^@][^>]*@[^>]+\.[^>]+>$",
-            // Match a GitHub username plus email address.
-            r"^[^()<>,@]+ \(@[a-zA-Z\d-]+\) <[^@][^>]*@[^>]+\.[^>]+>$",
-            // Match just a name.
-            r"^[^()<>,@]+$",
-        ])
-        .unwrap();
-
+        let name_count = field.name().len();
         let mut has_username = false;
-        let mut offset = 0;
-
-        for item in items {
-            let current = offset;
-            let item_count = item.len();
-            offset += item_count + 1;
-            let trimmed = item.trim();
-
-            let matches = set.matches(trimmed);
 
-            if matches.matched_any() {
-                has_username |= matches.matched(0);
-                has_username |= matches.matched(2);
-                continue;
+        for result in author::parse(field.value()) {
+            let error = match result {
+                Ok(author) => {
+                    has_username |= author.handle().is_some();
+                    continue;
+                }
+                Err(e) => e,
+            };
+
+            // Empty items are reported by `preamble-list-author` instead.
+            if let AuthorError::MissingName { span } = &error {
+                if field.value()[span.clone()].trim().is_empty() {
+                    continue;
+                }
             }
 
-            let name_count = field.name().len();
+            let label = match error {
+                AuthorError::MissingName { .. } => "expected a name",
+                AuthorError::UnterminatedQuote { .. } => "missing closing `\"`",
+                AuthorError::UnexpectedCharacter { .. } => "unexpected character",
+                AuthorError::MissingSpace { .. } => "add a space before this",
+                AuthorError::UnterminatedHandle { .. } => "missing closing `)`",
+                AuthorError::InvalidHandle { .. } => "expected `(@username)`",
+                AuthorError::UnterminatedEmail { .. } => "missing closing `>`",
+                AuthorError::InvalidEmail { .. } => "not a valid email address",
+                AuthorError::TrailingText { .. } => "unexpected text",
+                _ => "unrecognized author",
+            };
+
+            let title = error.to_string();
+            let span = error.span();
 
-            let start = name_count + current + 1;
             ctx.report(
                 ctx.annotation_level()
-                    .title("authors in the preamble must match the expected format")
+                    .title(&title)
                     .id(slug)
                     .snippet(
                         Snippet::source(field.source())
@@ -90,8 +86,12 @@ where
                             .origin_opt(ctx.origin())
                             .annotation(
                                 ctx.annotation_level()
-                                    .span_utf8(field.source(), start, item_count)
-                                    .label("unrecognized author"),
+                                    .span_utf8(
+                                        field.source(),
+                                        name_count + 1 + span.start,
+                                        span.len().max(1),
+                                    )
+                                    .label(label),
                             ),
                     )
                     .footers(footer()),
@@ -0,0 +1,267 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_preamble::{Field, Preamble};
+use eipw_snippets::{Level, Snippet};
+
+use crate::{
+    lints::{Context, Error, Lint},
+    SnippetExt,
+};
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::{self, Debug, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-version", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum Rule {
+    /// The header must be present when the predicate holds.
+    RequiredIf,
+
+    /// The header must be present when the predicate does not hold.
+    RequiredUnless,
+
+    /// The header must be absent when the predicate holds.
+    ForbiddenIf,
+
+    /// The header must be absent when the predicate does not hold.
+    ForbiddenUnless,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-version", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum Predicate<S> {
+    Present(S),
+    OneOf { name: S, values: Vec<S> },
+    All(Vec<Predicate<S>>),
+    Any(Vec<Predicate<S>>),
+    Not(Box<Predicate<S>>),
+}
+
+impl<S> Predicate<S>
+where
+    S: AsRef<str>,
+{
+    fn eval(&self, preamble: &Preamble) -> bool {
+        match self {
+            Self::Present(name) => preamble.by_name(name.as_ref()).is_some(),
+            Self::OneOf { name, values } => match preamble.by_name(name.as_ref()) {
+                None => false,
+                Some(f) => {
+                    let value = f.value().trim();
+                    values.iter().any(|v| v.as_ref() == value)
+                }
+            },
+            Self::All(p) => p.iter().all(|p| p.eval(preamble)),
+            Self::Any(p) => p.iter().any(|p| p.eval(preamble)),
+            Self::Not(p) => !p.eval(preamble),
+        }
+    }
+
+    fn names<'s>(&'s self, names: &mut Vec<&'s str>) {
+        match self {
+            Self::Present(name) | Self::OneOf { name, .. } => {
+                if !names.contains(&name.as_ref()) {
+                    names.push(name.as_ref());
+                }
+            }
+            Self::All(p) | Self::Any(p) => p.iter().for_each(|p| p.names(names)),
+            Self::Not(p) => p.names(names),
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(self, Self::All(_) | Self::Any(_))
+    }
+
+    fn fmt_values(f: &mut fmt::Formatter<'_>, values: &[S]) -> fmt::Result {
+        let values: Vec<_> = values.iter().map(AsRef::as_ref).collect();
+        match values.as_slice() {
+            [value] => write!(f, "`{}`", value),
+            _ => write!(f, "one of `{}`", values.join("`, `")),
+        }
+    }
+
+    fn fmt_joined(f: &mut fmt::Formatter<'_>, items: &[Self], sep: &str) -> fmt::Result {
+        for (idx, item) in items.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(sep)?;
+            }
+
+            if item.is_compound() {
+                write!(f, "({})", item)?;
+            } else {
+                write!(f, "{}", item)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn map_to_str(&self) -> Predicate<&str> {
+        match self {
+            Self::Present(name) => Predicate::Present(name.as_ref()),
+            Self::OneOf { name, values } => Predicate::OneOf {
+                name: name.as_ref(),
+                values: values.iter().map(AsRef::as_ref).collect(),
+            },
+            Self::All(p) => Predicate::All(p.iter().map(Self::map_to_str).collect()),
+            Self::Any(p) => Predicate::Any(p.iter().map(Self::map_to_str).collect()),
+            Self::Not(p) => Predicate::Not(Box::new(p.map_to_str())),
+        }
+    }
+}
+
+impl<S> Display for Predicate<S>
+where
+    S: AsRef<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Present(name) => write!(f, "`{}` is present", name.as_ref()),
+            Self::OneOf { name, values } => {
+                write!(f, "`{}` is ", name.as_ref())?;
+                Self::fmt_values(f, values)
+            }
+            Self::All(p) => Self::fmt_joined(f, p, " and "),
+            Self::Any(p) => Self::fmt_joined(f, p, " or "),
+            Self::Not(p) => match &**p {
+                Self::Present(name) => write!(f, "`{}` is absent", name.as_ref()),
+                Self::OneOf { name, values } => {
+                    write!(f, "`{}` is not ", name.as_ref())?;
+                    Self::fmt_values(f, values)
+                }
+                other => write!(f, "not ({})", other),
+            },
+        }
+    }
+}
+
+impl From<Predicate<&str>> for Predicate<String> {
+    fn from(value: Predicate<&str>) -> Self {
+        match value {
+            Predicate::Present(name) => Self::Present(name.into()),
+            Predicate::OneOf { name, values } => Self::OneOf {
+                name: name.into(),
+                values: values.into_iter().map(Into::into).collect(),
+            },
+            Predicate::All(p) => Self::All(p.into_iter().map(Into::into).collect()),
+            Predicate::Any(p) => Self::Any(p.into_iter().map(Into::into).collect()),
+            Predicate::Not(p) => Self::Not(Box::new((*p).into())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-version", derive(schemars::JsonSchema))]
+pub struct Condition<S> {
+    pub name: S,
+    pub rule: Rule,
+    pub when: Predicate<S>,
+}
+
+impl<S> Condition<S>
+where
+    S: AsRef<str>,
+{
+    fn field_snippet<'a>(
+        ctx: &Context<'a, '_>,
+        field: &Field<'a>,
+        level: Level,
+        label: &'a str,
+    ) -> (usize, Snippet<'a>) {
+        (
+            field.line_start(),
+            Snippet::source(field.source())
+                .line_start(field.line_start())
+                .fold(false)
+                .origin_opt(ctx.origin())
+                .annotation(level.span(0..field.source().len()).label(label)),
+        )
+    }
+}
+
+impl<S> Lint for Condition<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let holds = self.when.eval(ctx.preamble());
+        let field = ctx.preamble().by_name(self.name.as_ref());
+
+        let (required, verb) = match self.rule {
+            Rule::RequiredIf => (true, "when"),
+            Rule::RequiredUnless => (true, "unless"),
+            Rule::ForbiddenIf => (false, "when"),
+            Rule::ForbiddenUnless => (false, "unless"),
+        };
+
+        let applies = match self.rule {
+            Rule::RequiredIf | Rule::ForbiddenIf => holds,
+            Rule::RequiredUnless | Rule::ForbiddenUnless => !holds,
+        };
+
+        if !applies || required == field.is_some() {
+            return Ok(());
+        }
+
+        let label = match (required, self.rule) {
+            (true, _) => format!(
+                "preamble header `{}` is required {} {}",
+                self.name, verb, self.when
+            ),
+            (false, Rule::ForbiddenUnless) => format!(
+                "preamble header `{}` is only allowed when {}",
+                self.name, self.when
+            ),
+            (false, _) => format!(
+                "preamble header `{}` is not allowed when {}",
+                self.name, self.when
+            ),
+        };
+
+        let mut names = Vec::new();
+        self.when.names(&mut names);
+
+        let mut snippets: Vec<_> = names
+            .into_iter()
+            .filter(|n| *n != self.name.as_ref())
+            .filter_map(|n| ctx.preamble().by_name(n))
+            .map(|f| Self::field_snippet(ctx, &f, Level::Info, "defined here"))
+            .collect();
+
+        if let Some(field) = field {
+            snippets.push(Self::field_snippet(
+                ctx,
+                &field,
+                ctx.annotation_level(),
+                "remove this",
+            ));
+        }
+
+        snippets.sort_by_key(|(line_start, _)| *line_start);
+
+        let mut message = ctx.annotation_level().title(&label).id(slug);
+
+        if snippets.is_empty() {
+            message = message.snippet(
+                Snippet::source("---")
+                    .line_start(1)
+                    .origin_opt(ctx.origin())
+                    .fold(true),
+            );
+        } else {
+            message = message.snippets(snippets.into_iter().map(|(_, s)| s));
+        }
+
+        ctx.report(message)?;
+
+        Ok(())
+    }
+}
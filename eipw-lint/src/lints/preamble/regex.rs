@@ -7,7 +7,7 @@
 use eipw_snippets::{Level, Snippet};
 
 use crate::{
-    lints::{Context, Error, Lint},
+    lints::{Compile, Context, Error, Lint},
     LevelExt, SnippetExt,
 };
 
@@ -33,20 +33,38 @@ pub struct Regex<S> {
     pub message: S,
 }
 
-impl<S> Lint for Regex<S>
+impl<S> Compile for Regex<S>
 where
     S: Debug + Display + AsRef<str>,
 {
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
+        Ok(Box::new(Compiled {
+            name: self.name.as_ref().to_owned(),
+            mode: self.mode,
+            re: ::regex::Regex::new(self.pattern.as_ref()).map_err(Error::custom)?,
+            message: self.message.as_ref().to_owned(),
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct Compiled {
+    name: String,
+    mode: Mode,
+    re: ::regex::Regex,
+    message: String,
+}
+
+impl Lint for Compiled {
     fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
-        let field = match ctx.preamble().by_name(self.name.as_ref()) {
+        let field = match ctx.preamble().by_name(&self.name) {
             None => return Ok(()),
             Some(s) => s,
         };
 
         let value = field.value().trim();
 
-        let re = ::regex::Regex::new(self.pattern.as_ref()).map_err(Error::custom)?;
-        let matches = re.is_match(value);
+        let matches = self.re.is_match(value);
 
         let slice_label = match (self.mode, matches) {
             (Mode::Includes, true) => return Ok(()),
@@ -56,7 +74,7 @@ where
             (Mode::Excludes, true) => "prohibited pattern was matched",
         };
 
-        let footer_label = format!("the pattern in question: `{}`", self.pattern);
+        let footer_label = format!("the pattern in question: `{}`", self.re.as_str());
 
         // TODO: Actually highlight the matches for `Mode::Excludes`, and not
         //       just the whole value.
@@ -66,7 +84,7 @@ where
 
         ctx.report(
             ctx.annotation_level()
-                .title(self.message.as_ref())
+                .title(&self.message)
                 .id(slug)
                 .snippet(
                     Snippet::source(field.source())
@@ -0,0 +1,198 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_preamble::Field;
+use eipw_snippets::{Level, Snippet};
+
+use chrono::{NaiveDate, Utc};
+
+use crate::{
+    lints::{Compile, Context, Error, Lint},
+    LevelExt, SnippetExt,
+};
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt::{Debug, Display};
+
+const FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-version", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct Timeline<S> {
+    pub created: S,
+    pub deadline: S,
+    pub status: S,
+
+    /// Value of `status` for which `deadline` must not have passed.
+    pub deadline_status: S,
+
+    /// Date to compare against instead of the current date (in UTC.)
+    #[serde(
+        default = "Option::default",
+        skip_serializing_if = "Option::<S>::is_none"
+    )]
+    pub today: Option<S>,
+}
+
+impl<S> Compile for Timeline<S>
+where
+    S: Debug + Display + AsRef<str>,
+{
+    fn compile(&self) -> Result<Box<dyn Lint>, Error> {
+        let today = match &self.today {
+            Some(t) => Some(NaiveDate::parse_from_str(t.as_ref(), FORMAT).map_err(Error::custom)?),
+            None => None,
+        };
+
+        Ok(Box::new(Compiled {
+            created: self.created.as_ref().to_owned(),
+            deadline: self.deadline.as_ref().to_owned(),
+            status: self.status.as_ref().to_owned(),
+            deadline_status: self.deadline_status.as_ref().to_owned(),
+            today,
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct Compiled {
+    created: String,
+    deadline: String,
+    status: String,
+    deadline_status: String,
+    today: Option<NaiveDate>,
+}
+
+impl Compiled {
+    fn today(&self) -> NaiveDate {
+        self.today.unwrap_or_else(|| Utc::now().date_naive())
+    }
+
+    fn date<'a>(&self, ctx: &Context<'a, '_>, name: &str) -> Option<(Field<'a>, NaiveDate)> {
+        let field = ctx.preamble().by_name(name)?;
+        let date = NaiveDate::parse_from_str(field.value().trim(), FORMAT).ok()?;
+        Some((field, date))
+    }
+}
+
+fn value_snippet<'a, 'l>(
+    ctx: &Context<'a, '_>,
+    field: &Field<'a>,
+    level: Level,
+    label: &'l str,
+) -> Snippet<'l>
+where
+    'a: 'l,
+{
+    let name_count = field.name().len();
+    let value_count = field.value().len();
+
+    Snippet::source(field.source())
+        .fold(false)
+        .line_start(field.line_start())
+        .origin_opt(ctx.origin())
+        .annotation(
+            level
+                .span_utf8(field.source(), name_count + 1, value_count)
+                .label(label),
+        )
+}
+
+impl Lint for Compiled {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        let today = self.today();
+        let created = self.date(ctx, &self.created);
+        let deadline = self.date(ctx, &self.deadline);
+
+        if let Some((field, date)) = &created {
+            if *date > today {
+                let label = format!("preamble header `{}` is in the future", self.created);
+                let slice_label = format!("after today ({})", today.format(FORMAT));
+
+                ctx.report(
+                    ctx.annotation_level()
+                        .title(&label)
+                        .id(slug)
+                        .snippet(value_snippet(
+                            ctx,
+                            field,
+                            ctx.annotation_level(),
+                            &slice_label,
+                        )),
+                )?;
+            }
+        }
+
+        if let (Some((created_field, created)), Some((deadline_field, deadline))) =
+            (&created, &deadline)
+        {
+            if deadline <= created {
+                let label = format!(
+                    "preamble header `{}` must be after `{}`",
+                    self.deadline, self.created
+                );
+
+                let mut snippets = vec![
+                    (
+                        created_field.line_start(),
+                        value_snippet(ctx, created_field, Level::Info, "created here"),
+                    ),
+                    (
+                        deadline_field.line_start(),
+                        value_snippet(
+                            ctx,
+                            deadline_field,
+                            ctx.annotation_level(),
+                            "not after creation",
+                        ),
+                    ),
+                ];
+
+                snippets.sort_by_key(|(line_start, _)| *line_start);
+
+                ctx.report(
+                    ctx.annotation_level()
+                        .title(&label)
+                        .id(slug)
+                        .snippets(snippets.into_iter().map(|(_, s)| s)),
+                )?;
+            }
+        }
+
+        let in_deadline_status = ctx
+            .preamble()
+            .by_name(&self.status)
+            .map(|f| f.value().trim() == self.deadline_status)
+            .unwrap_or(false);
+
+        if let Some((field, date)) = &deadline {
+            if in_deadline_status && *date < today {
+                let label = format!(
+                    "preamble header `{}` has passed while `{}` is `{}`",
+                    self.deadline, self.status, self.deadline_status,
+                );
+                let slice_label = format!("before today ({})", today.format(FORMAT));
+
+                ctx.report(
+                    ctx.annotation_level()
+                        .title(&label)
+                        .id(slug)
+                        .snippet(value_snippet(
+                            ctx,
+                            field,
+                            ctx.annotation_level(),
+                            &slice_label,
+                        ))
+                        .footer(Level::Help.title("extend the deadline or update the status")),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
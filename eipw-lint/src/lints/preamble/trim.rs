@@ -4,7 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use eipw_snippets::Snippet;
+use eipw_snippets::{Snippet, Suggestion};
 
 use crate::{
     lints::{Context, Error, Lint},
@@ -51,7 +51,11 @@ impl Lint for Trim {
                             ctx.annotation_level()
                                 .span_utf8(field.source(), name_count + 1, value_count)
                                 .label("value has extra whitespace"),
-                        ),
+                        )
+                        .suggestion(Suggestion::new(
+                            name_count + 1..name_count + 1 + value_count,
+                            format!(" {}", value.trim()),
+                        )),
                 ),
             )?;
         }
@@ -68,6 +72,7 @@ impl Lint for Trim {
                             .span_utf8(n.source(), name_count + 1, 1)
                             .label("space required here"),
                     )
+                    .suggestion(Suggestion::new(name_count + 1..name_count + 1, " "))
             });
 
             ctx.report(
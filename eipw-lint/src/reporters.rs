@@ -5,19 +5,42 @@
  */
 
 pub mod additional_help;
+pub mod checkstyle;
 pub mod count;
+pub mod filter;
+pub mod gitlab;
+pub mod html;
 pub mod json;
+pub mod junit;
+pub mod markdown;
+pub mod min_level;
+pub mod ndjson;
 pub mod null;
+pub mod rdjson;
+pub mod sorted;
+pub mod tee;
 pub mod text;
 
-use eipw_snippets::Message;
+use eipw_snippets::{Annotation, Message, Snippet};
 
 pub use self::additional_help::AdditionalHelp;
+pub use self::checkstyle::Checkstyle;
 pub use self::count::Count;
+pub use self::filter::{Except, Only};
+pub use self::gitlab::Gitlab;
+pub use self::html::Html;
 pub use self::json::Json;
+pub use self::junit::Junit;
+pub use self::markdown::Markdown;
+pub use self::min_level::MinLevel;
+pub use self::ndjson::Ndjson;
 pub use self::null::Null;
+pub use self::rdjson::{Rdjson, Rdjsonl};
+pub use self::sorted::Sorted;
+pub use self::tee::Tee;
 pub use self::text::Text;
 
+use std::collections::HashMap;
 use std::fmt::{self, Debug};
 
 #[derive(Debug)]
@@ -51,3 +74,147 @@ impl Error {
 pub trait Reporter {
     fn report(&self, snippet: Message<'_>) -> Result<(), Error>;
 }
+
+const UNKNOWN_ORIGIN: &str = "(unknown)";
+
+/// Where a message points, for formats that only support a single position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Location<'a> {
+    origin: Option<&'a str>,
+    line: Option<usize>,
+
+    /// Counted in characters, starting at one.
+    column: Option<usize>,
+}
+
+impl<'a> Location<'a> {
+    /// Use the first annotation matching the message's level, falling back
+    /// to any annotation, and then to the first snippet.
+    fn of(message: &'a Message<'_>) -> Self {
+        let origin = message.snippets.iter().find_map(|s| s.origin.as_deref());
+
+        match primary(message) {
+            Some((snippet, annotation)) => {
+                let position = snippet.position(annotation.range.start);
+                Self {
+                    origin,
+                    line: Some(position.line),
+                    column: Some(position.column_chars),
+                }
+            }
+            None => Self {
+                origin,
+                line: message.snippets.first().map(|s| s.line_start),
+                column: None,
+            },
+        }
+    }
+}
+
+/// Values collected per origin (usually a file path), in the order each
+/// origin was first seen.
+#[derive(Debug)]
+struct ByOrigin<T> {
+    index: HashMap<String, usize>,
+    values: Vec<(String, T)>,
+}
+
+impl<T> Default for ByOrigin<T> {
+    fn default() -> Self {
+        Self {
+            index: HashMap::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<T> ByOrigin<T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.values.iter().map(|(o, v)| (o.as_str(), v))
+    }
+}
+
+impl<T> ByOrigin<T>
+where
+    T: Default,
+{
+    /// The value for `origin`, starting from the default if it's new.
+    fn get_mut(&mut self, origin: &str) -> &mut T {
+        let idx = match self.index.get(origin) {
+            Some(idx) => *idx,
+            None => {
+                self.values.push((origin.to_owned(), T::default()));
+                self.index.insert(origin.to_owned(), self.values.len() - 1);
+                self.values.len() - 1
+            }
+        };
+
+        &mut self.values[idx].1
+    }
+
+    /// The value for the origin `message` points at.
+    fn of(&mut self, message: &Message<'_>) -> &mut T {
+        self.get_mut(Location::of(message).origin.unwrap_or(UNKNOWN_ORIGIN))
+    }
+}
+
+impl<T> IntoIterator for ByOrigin<T> {
+    type Item = (String, T);
+    type IntoIter = std::vec::IntoIter<(String, T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+/// Format `count` and `noun`, pluralized for English.
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+/// The annotation (and its snippet) that best represents `message`: the first
+/// one matching the message's level, or failing that, the first one.
+fn primary<'m, 'a>(message: &'m Message<'a>) -> Option<(&'m Snippet<'a>, &'m Annotation<'a>)> {
+    let annotated = message
+        .snippets
+        .iter()
+        .flat_map(|s| s.annotations.iter().map(move |a| (s, a)));
+
+    annotated
+        .clone()
+        .find(|(_, a)| a.level == message.level)
+        .or_else(|| annotated.clone().next())
+}
+
+/// Escape text for use in XML attributes and character data, dropping
+/// characters XML 1.0 can't represent.
+fn escape_xml(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            '\t' | '\n' | '\r' => output.push(c),
+            c if c.is_control() => (),
+            c => output.push(c),
+        }
+    }
+
+    output
+}
@@ -0,0 +1,91 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::{Level, Message};
+
+use std::cell::RefCell;
+use std::fmt::Write;
+
+use super::{escape_xml, ByOrigin, Error, Location, Reporter};
+
+#[derive(Debug)]
+struct Entry {
+    line: Option<usize>,
+    column: Option<usize>,
+    severity: &'static str,
+    message: String,
+    source: String,
+}
+
+/// Collects messages into a Checkstyle XML report.
+#[derive(Debug, Default)]
+pub struct Checkstyle {
+    files: RefCell<ByOrigin<Vec<Entry>>>,
+}
+
+impl Checkstyle {
+    pub fn into_xml(self) -> String {
+        let mut xml = String::new();
+        writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+        writeln!(xml, r#"<checkstyle version="4.3">"#).unwrap();
+
+        for (name, entries) in self.files.into_inner() {
+            writeln!(xml, r#"  <file name="{}">"#, escape_xml(&name)).unwrap();
+
+            for entry in entries {
+                write!(xml, "    <error").unwrap();
+                if let Some(line) = entry.line {
+                    write!(xml, r#" line="{}""#, line).unwrap();
+                }
+                if let Some(column) = entry.column {
+                    write!(xml, r#" column="{}""#, column).unwrap();
+                }
+                writeln!(
+                    xml,
+                    r#" severity="{}" message="{}" source="{}"/>"#,
+                    entry.severity,
+                    escape_xml(&entry.message),
+                    escape_xml(&entry.source),
+                )
+                .unwrap();
+            }
+
+            writeln!(xml, "  </file>").unwrap();
+        }
+
+        writeln!(xml, "</checkstyle>").unwrap();
+        xml
+    }
+}
+
+impl Reporter for Checkstyle {
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let location = Location::of(&message);
+
+        let severity = match message.level {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Info | Level::Note | Level::Help => "info",
+        };
+
+        let source = match message.id {
+            Some(ref id) => format!("eipw.{}", id),
+            None => "eipw".to_owned(),
+        };
+
+        let entry = Entry {
+            line: location.line,
+            column: location.column,
+            severity,
+            message: message.title.to_string(),
+            source,
+        };
+
+        self.files.borrow_mut().of(&message).push(entry);
+
+        Ok(())
+    }
+}
@@ -6,11 +6,16 @@
 
 use eipw_snippets::{Level, Message};
 
+use serde::Serialize;
+
 use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::iter::Sum;
+use std::ops::AddAssign;
 
 use super::{Error, Reporter};
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 #[non_exhaustive]
 pub struct Counts {
     pub error: usize,
@@ -20,10 +25,44 @@ pub struct Counts {
     pub help: usize,
 }
 
+impl Counts {
+    pub(crate) fn add(&mut self, level: Level) {
+        match level {
+            Level::Error => self.error += 1,
+            Level::Warning => self.warning += 1,
+            Level::Info => self.info += 1,
+            Level::Note => self.note += 1,
+            Level::Help => self.help += 1,
+        }
+    }
+}
+
+impl AddAssign for Counts {
+    fn add_assign(&mut self, other: Self) {
+        self.error += other.error;
+        self.warning += other.warning;
+        self.info += other.info;
+        self.note += other.note;
+        self.help += other.help;
+    }
+}
+
+impl Sum for Counts {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut total = Self::default();
+        for counts in iter {
+            total += counts;
+        }
+        total
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Count<T> {
     inner: T,
     counts: RefCell<Counts>,
+    lints: RefCell<BTreeMap<String, Counts>>,
+    origins: RefCell<BTreeMap<String, Counts>>,
 }
 
 impl<T> Reporter for Count<T>
@@ -31,14 +70,22 @@ where
     T: Reporter,
 {
     fn report(&self, message: Message<'_>) -> Result<(), Error> {
-        let mut counts = self.counts.borrow_mut();
-
-        match message.level {
-            Level::Error => counts.error += 1,
-            Level::Warning => counts.warning += 1,
-            Level::Info => counts.info += 1,
-            Level::Note => counts.note += 1,
-            Level::Help => counts.help += 1,
+        self.counts.borrow_mut().add(message.level);
+
+        if let Some(ref id) = message.id {
+            self.lints
+                .borrow_mut()
+                .entry(id.to_string())
+                .or_default()
+                .add(message.level);
+        }
+
+        if let Some(origin) = message.snippets.iter().find_map(|s| s.origin.as_deref()) {
+            self.origins
+                .borrow_mut()
+                .entry(origin.to_owned())
+                .or_default()
+                .add(message.level);
         }
 
         self.inner.report(message)
@@ -50,6 +97,8 @@ impl<T> Count<T> {
         Self {
             inner,
             counts: Default::default(),
+            lints: Default::default(),
+            origins: Default::default(),
         }
     }
 
@@ -60,4 +109,14 @@ impl<T> Count<T> {
     pub fn counts(&self) -> Counts {
         *self.counts.borrow()
     }
+
+    /// Counts for each lint slug that reported at least one message.
+    pub fn counts_by_lint(&self) -> BTreeMap<String, Counts> {
+        self.lints.borrow().clone()
+    }
+
+    /// Counts for each origin (usually a file path) with at least one message.
+    pub fn counts_by_origin(&self) -> BTreeMap<String, Counts> {
+        self.origins.borrow().clone()
+    }
 }
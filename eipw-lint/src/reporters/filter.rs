@@ -0,0 +1,84 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::Message;
+
+use std::collections::HashSet;
+
+use super::{Error, Reporter};
+
+/// Forwards only messages from the given lints, dropping the rest (including
+/// messages without a slug.)
+#[derive(Debug)]
+pub struct Only<T> {
+    inner: T,
+    slugs: HashSet<String>,
+}
+
+impl<T> Only<T> {
+    pub fn new<I, S>(inner: T, slugs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            inner,
+            slugs: slugs.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> Reporter for Only<T>
+where
+    T: Reporter,
+{
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        match message.id {
+            Some(ref id) if self.slugs.contains(id.as_ref()) => self.inner.report(message),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Drops messages from the given lints, forwarding the rest.
+#[derive(Debug)]
+pub struct Except<T> {
+    inner: T,
+    slugs: HashSet<String>,
+}
+
+impl<T> Except<T> {
+    pub fn new<I, S>(inner: T, slugs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            inner,
+            slugs: slugs.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> Reporter for Except<T>
+where
+    T: Reporter,
+{
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        match message.id {
+            Some(ref id) if self.slugs.contains(id.as_ref()) => Ok(()),
+            _ => self.inner.report(message),
+        }
+    }
+}
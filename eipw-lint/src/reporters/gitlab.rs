@@ -0,0 +1,143 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::{Level, Message};
+
+use serde::Serialize;
+
+use sha3::{Digest, Sha3_256};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use super::{Error, Location, Reporter, UNKNOWN_ORIGIN};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Info,
+    Minor,
+    Major,
+}
+
+#[derive(Debug, Serialize)]
+struct Lines {
+    begin: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct IssueLocation {
+    path: String,
+    lines: Lines,
+}
+
+#[derive(Debug, Serialize)]
+struct Issue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: Severity,
+    location: IssueLocation,
+}
+
+/// Collects messages into a GitLab Code Quality report.
+#[derive(Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct Gitlab {
+    issues: RefCell<Vec<Issue>>,
+
+    #[serde(skip)]
+    occurrences: RefCell<HashMap<Vec<u8>, u64>>,
+}
+
+impl Gitlab {
+    /// Identifies a message by its slug, origin, and the (whitespace
+    /// normalized) source it annotates, so it survives unrelated edits that
+    /// move it to another line.
+    ///
+    /// `occurrence` counts the earlier messages in the same report that are
+    /// otherwise identical, which keeps repeated problems (like the same typo
+    /// twice on one line) from sharing a fingerprint.
+    pub fn fingerprint(message: &Message<'_>, occurrence: u64) -> String {
+        let hasher = Sha3_256::new()
+            .chain_update(Self::identity(message))
+            .chain_update(occurrence.to_le_bytes());
+
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn identity(message: &Message<'_>) -> Vec<u8> {
+        let mut hasher = Sha3_256::new();
+
+        let mut field = |text: &str| {
+            hasher.update((text.len() as u64).to_le_bytes());
+            hasher.update(text);
+        };
+
+        field(message.id.as_deref().unwrap_or_default());
+        field(
+            message
+                .snippets
+                .iter()
+                .find_map(|s| s.origin.as_deref())
+                .unwrap_or_default(),
+        );
+
+        if message.snippets.is_empty() {
+            field(&message.title);
+        }
+
+        for snippet in &message.snippets {
+            field(&normalize(&snippet.source));
+
+            for annotation in &snippet.annotations {
+                let text = snippet.source.get(annotation.range.clone());
+                field(&normalize(text.unwrap_or_default()));
+            }
+        }
+
+        hasher.finalize().to_vec()
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl Reporter for Gitlab {
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let location = Location::of(&message);
+
+        let severity = match message.level {
+            Level::Error => Severity::Major,
+            Level::Warning => Severity::Minor,
+            Level::Info | Level::Note | Level::Help => Severity::Info,
+        };
+
+        let occurrence = {
+            let mut occurrences = self.occurrences.borrow_mut();
+            let count = occurrences.entry(Self::identity(&message)).or_default();
+            *count += 1;
+            *count - 1
+        };
+
+        let issue = Issue {
+            description: message.title.to_string(),
+            check_name: message.id.as_deref().unwrap_or("eipw").to_owned(),
+            fingerprint: Self::fingerprint(&message, occurrence),
+            severity,
+            location: IssueLocation {
+                path: location.origin.unwrap_or(UNKNOWN_ORIGIN).to_owned(),
+                lines: Lines {
+                    begin: location.line.unwrap_or(1),
+                },
+            },
+        };
+
+        self.issues.borrow_mut().push(issue);
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::{Level, Message, Snippet};
+
+use std::cell::RefCell;
+use std::fmt::Write;
+
+use super::count::Counts;
+use super::{escape_xml as escape, plural, ByOrigin, Error, Reporter};
+
+const REPORT_CSS: &str = r#"
+nav li, .message li { margin: 0.25em 0; }
+.message { margin: 1em 0 2em; }
+.message h3 { font-size: 1em; }
+.message.error .level, mark.error { color: #b00020; }
+.message.warning .level, mark.warning { color: #8a5a00; }
+mark { background: transparent; font-weight: bold; text-decoration: underline wavy; }
+mark.info, mark.note, mark.help { color: #005a9c; }
+.line-number { color: #888; user-select: none; }
+"#;
+
+#[derive(Debug, Default)]
+struct File {
+    counts: Counts,
+    messages: Vec<String>,
+}
+
+/// Collects messages into a single, self-contained HTML page, grouped by
+/// source file.
+#[derive(Debug, Default)]
+pub struct Html {
+    stylesheet: String,
+    docs_url: Option<String>,
+    files: RefCell<ByOrigin<File>>,
+}
+
+impl Html {
+    /// Embed `css` into the page, before the report's own styles.
+    pub fn with_stylesheet<S: Into<String>>(mut self, css: S) -> Self {
+        self.stylesheet = css.into();
+        self
+    }
+
+    /// Link each lint to its documentation at `{url}{slug}/`.
+    pub fn with_docs_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.docs_url = Some(url.into());
+        self
+    }
+
+    pub fn into_html(self) -> String {
+        let files = self.files.into_inner();
+
+        let total: Counts = files.iter().map(|(_, f)| f.counts).sum();
+
+        let mut html = String::new();
+        writeln!(html, "<!DOCTYPE html>").unwrap();
+        writeln!(html, r#"<html lang="en">"#).unwrap();
+        writeln!(html, "\t<head>").unwrap();
+        writeln!(html, "\t\t<meta charset=\"UTF-8\">").unwrap();
+        writeln!(html, "\t\t<title>eipw report</title>").unwrap();
+        writeln!(
+            html,
+            "\t\t<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">"
+        )
+        .unwrap();
+        writeln!(
+            html,
+            "\t\t<style>\n{}\n{}\t\t</style>",
+            self.stylesheet.replace("</", "<\\/"),
+            REPORT_CSS,
+        )
+        .unwrap();
+        writeln!(html, "\t</head>").unwrap();
+        writeln!(html, "\t<body>").unwrap();
+        writeln!(html, "\t\t<article>").unwrap();
+        writeln!(html, "\t\t\t<h1>eipw report</h1>").unwrap();
+
+        if files.is_empty() {
+            writeln!(html, "\t\t\t<p>No problems found.</p>").unwrap();
+        } else {
+            writeln!(
+                html,
+                "\t\t\t<p>Found {} and {} in {}.</p>",
+                plural(total.error, "error"),
+                plural(total.warning, "warning"),
+                plural(files.len(), "file"),
+            )
+            .unwrap();
+
+            writeln!(html, "\t\t\t<nav>\n\t\t\t\t<ul>").unwrap();
+            for (idx, (name, file)) in files.iter().enumerate() {
+                writeln!(
+                    html,
+                    "\t\t\t\t\t<li><a href=\"#file-{}\"><code>{}</code></a>: {}, {}</li>",
+                    idx,
+                    escape(name),
+                    plural(file.counts.error, "error"),
+                    plural(file.counts.warning, "warning"),
+                )
+                .unwrap();
+            }
+            writeln!(html, "\t\t\t\t</ul>\n\t\t\t</nav>").unwrap();
+
+            for (idx, (name, file)) in files.into_iter().enumerate() {
+                writeln!(html, "\t\t\t<section id=\"file-{}\">", idx).unwrap();
+                writeln!(html, "\t\t\t\t<h2><code>{}</code></h2>", escape(&name)).unwrap();
+                for message in file.messages {
+                    html.push_str(&message);
+                }
+                writeln!(html, "\t\t\t</section>").unwrap();
+            }
+        }
+
+        writeln!(html, "\t\t</article>").unwrap();
+        writeln!(html, "\t</body>").unwrap();
+        writeln!(html, "</html>").unwrap();
+        html
+    }
+
+    fn render(&self, message: &Message<'_>) -> String {
+        let mut html = String::new();
+        let level = level_name(message.level);
+
+        writeln!(html, "\t\t\t\t<div class=\"message {}\">", level).unwrap();
+        write!(html, "\t\t\t\t\t<h3><span class=\"level\">{}</span>", level).unwrap();
+
+        if let Some(ref id) = message.id {
+            match self.docs_url {
+                Some(ref url) => write!(
+                    html,
+                    "[<a href=\"{}{}/\"><code>{}</code></a>]",
+                    escape(url),
+                    escape(id),
+                    escape(id)
+                ),
+                None => write!(html, "[<code>{}</code>]", escape(id)),
+            }
+            .unwrap();
+        }
+
+        writeln!(html, ": {}</h3>", escape(&message.title)).unwrap();
+
+        for snippet in &message.snippets {
+            write!(html, "\t\t\t\t\t<pre>").unwrap();
+            highlight(&mut html, snippet);
+            writeln!(html, "</pre>").unwrap();
+        }
+
+        if !message.footer.is_empty() {
+            writeln!(html, "\t\t\t\t\t<ul>").unwrap();
+            for footer in &message.footer {
+                writeln!(
+                    html,
+                    "\t\t\t\t\t\t<li><strong>{}</strong>: {}</li>",
+                    level_name(footer.level),
+                    escape(&footer.title)
+                )
+                .unwrap();
+            }
+            writeln!(html, "\t\t\t\t\t</ul>").unwrap();
+        }
+
+        writeln!(html, "\t\t\t\t</div>").unwrap();
+        html
+    }
+}
+
+fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warning => "warning",
+        Level::Info => "info",
+        Level::Note => "note",
+        Level::Help => "help",
+    }
+}
+
+/// Write the snippet's source with line numbers, wrapping annotated ranges in
+/// `<mark>` elements.
+fn highlight(html: &mut String, snippet: &Snippet<'_>) {
+    let source = &*snippet.source;
+
+    let clamp = |mut idx: usize| {
+        idx = idx.min(source.len());
+        while !source.is_char_boundary(idx) {
+            idx -= 1;
+        }
+        idx
+    };
+
+    let annotations: Vec<_> = snippet
+        .annotations
+        .iter()
+        .map(|a| (clamp(a.range.start)..clamp(a.range.end), a))
+        .filter(|(r, _)| !r.is_empty())
+        .collect();
+
+    // Split the source wherever an annotation starts or ends, and after
+    // every newline, so each piece is covered by a fixed set of annotations.
+    let mut boundaries = vec![0, source.len()];
+    for (range, _) in &annotations {
+        boundaries.push(range.start);
+        boundaries.push(range.end);
+    }
+    boundaries.extend(source.match_indices('\n').map(|(i, _)| i + 1));
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let width = (snippet.line_start + source.matches('\n').count())
+        .to_string()
+        .len();
+    let mut line = snippet.line_start;
+
+    for window in boundaries.windows(2) {
+        let (start, end) = (window[0], window[1]);
+
+        if start == 0 || source[..start].ends_with('\n') {
+            write!(
+                html,
+                "<span class=\"line-number\">{:>width$} | </span>",
+                line,
+            )
+            .unwrap();
+            line += 1;
+        }
+
+        let text = &source[start..end];
+        let (text, newline) = match text.strip_suffix('\n') {
+            Some(t) => (t, "\n"),
+            None => (text, ""),
+        };
+
+        let covering: Vec<_> = annotations
+            .iter()
+            .filter(|(r, _)| r.start <= start && end <= r.end)
+            .map(|(_, a)| a)
+            .collect();
+
+        match covering.first() {
+            Some(first) if !text.is_empty() => {
+                let labels: Vec<_> = covering.iter().filter_map(|a| a.label.as_deref()).collect();
+                write!(html, "<mark class=\"{}\"", level_name(first.level)).unwrap();
+                if !labels.is_empty() {
+                    write!(html, " title=\"{}\"", escape(&labels.join("\n"))).unwrap();
+                }
+                write!(html, ">{}</mark>", escape(text)).unwrap();
+            }
+            _ => html.push_str(&escape(text)),
+        }
+
+        html.push_str(newline);
+    }
+}
+
+impl Reporter for Html {
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let rendered = self.render(&message);
+
+        let mut files = self.files.borrow_mut();
+        let file = files.of(&message);
+        file.counts.add(message.level);
+        file.messages.push(rendered);
+
+        Ok(())
+    }
+}
@@ -21,18 +21,67 @@ pub struct Json {
     reports: RefCell<Vec<Value>>,
 }
 
-impl Reporter for Json {
-    fn report(&self, message: Message<'_>) -> Result<(), Error> {
-        let mut value = serde_json::to_value(&message).map_err(Error::new)?;
-        let obj = value.as_object_mut().unwrap();
+/// Convert `message` into the JSON representation shared by [`Json`] and
+/// [`super::Ndjson`].
+pub(crate) fn to_value(message: &Message<'_>) -> Result<Value, Error> {
+    let mut value = serde_json::to_value(message).map_err(Error::new)?;
+    add_spans(&mut value, message)?;
+    let obj = value.as_object_mut().unwrap();
 
-        // Because `Message` borrows while deserializing, it breaks with
-        // escaped characters, so we pre-format the errors here.
-        let renderer = Renderer::plain();
-        let rendered = renderer.render((&message).into());
-        let formatted = format!("{}", rendered);
-        obj.insert("formatted".into(), Value::String(formatted));
+    // Consumers that can't render messages themselves (like the JavaScript
+    // bindings) use the pre-formatted text.
+    let renderer = Renderer::plain();
+    let rendered = renderer.render(message.into());
+    let formatted = format!("{}", rendered);
+    obj.insert("formatted".into(), Value::String(formatted));
+
+    Ok(value)
+}
+
+/// Resolve annotation and suggestion ranges into lines and columns, so
+/// consumers don't have to.
+fn add_spans(value: &mut Value, message: &Message<'_>) -> Result<(), Error> {
+    for (snippet, snippet_value) in message
+        .snippets
+        .iter()
+        .zip(value["snippets"].as_array_mut().unwrap())
+    {
+        let ranges = snippet
+            .annotations
+            .iter()
+            .map(|a| ("annotations", &a.range))
+            .enumerate()
+            .chain(
+                snippet
+                    .suggestions
+                    .iter()
+                    .map(|s| ("suggestions", &s.range))
+                    .enumerate(),
+            );
+
+        for (idx, (field, range)) in ranges {
+            let span = serde_json::to_value(snippet.span(range)).map_err(Error::new)?;
+            snippet_value[field][idx]
+                .as_object_mut()
+                .unwrap()
+                .insert("span".into(), span);
+        }
+    }
 
+    for (footer, footer_value) in message
+        .footer
+        .iter()
+        .zip(value["footer"].as_array_mut().unwrap())
+    {
+        add_spans(footer_value, footer)?;
+    }
+
+    Ok(())
+}
+
+impl Reporter for Json {
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let value = to_value(&message)?;
         self.reports.borrow_mut().push(value);
         Ok(())
     }
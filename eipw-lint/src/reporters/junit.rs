@@ -0,0 +1,123 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::annotate_snippets::Renderer;
+use eipw_snippets::{Level, Message};
+
+use std::cell::RefCell;
+use std::fmt::Write;
+
+use super::{escape_xml as escape, ByOrigin, Error, Reporter};
+
+#[derive(Debug, Default)]
+struct Failure {
+    id: Option<String>,
+    title: String,
+    rendered: String,
+}
+
+#[derive(Debug, Default)]
+struct Case {
+    failures: Vec<Failure>,
+    output: String,
+}
+
+/// Collects messages into a JUnit XML report, with one test case per source
+/// file and one failure per error.
+#[derive(Debug, Default)]
+pub struct Junit {
+    cases: RefCell<ByOrigin<Case>>,
+}
+
+impl Junit {
+    /// Include `origin` in the report, even if it has no messages.
+    pub fn add_source(&self, origin: &str) {
+        self.cases.borrow_mut().get_mut(origin);
+    }
+
+    pub fn into_xml(self) -> String {
+        let cases = self.cases.into_inner();
+        let tests = cases.len();
+        let failures = cases.iter().filter(|(_, c)| !c.failures.is_empty()).count();
+
+        let mut xml = String::new();
+        writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+        writeln!(
+            xml,
+            r#"<testsuites name="eipw" tests="{}" failures="{}">"#,
+            tests, failures
+        )
+        .unwrap();
+        writeln!(
+            xml,
+            r#"  <testsuite name="eipw" tests="{}" failures="{}" errors="0" skipped="0">"#,
+            tests, failures
+        )
+        .unwrap();
+
+        for (name, case) in cases {
+            let name = escape(&name);
+
+            if case.failures.is_empty() && case.output.is_empty() {
+                writeln!(xml, r#"    <testcase name="{}" classname="eipw"/>"#, name).unwrap();
+                continue;
+            }
+
+            writeln!(xml, r#"    <testcase name="{}" classname="eipw">"#, name).unwrap();
+
+            for failure in case.failures {
+                write!(
+                    xml,
+                    r#"      <failure message="{}""#,
+                    escape(&failure.title)
+                )
+                .unwrap();
+                if let Some(id) = failure.id {
+                    write!(xml, r#" type="{}""#, escape(&id)).unwrap();
+                }
+                writeln!(xml, ">{}</failure>", escape(&failure.rendered)).unwrap();
+            }
+
+            if !case.output.is_empty() {
+                writeln!(
+                    xml,
+                    "      <system-out>{}</system-out>",
+                    escape(&case.output)
+                )
+                .unwrap();
+            }
+
+            writeln!(xml, "    </testcase>").unwrap();
+        }
+
+        writeln!(xml, "  </testsuite>").unwrap();
+        writeln!(xml, "</testsuites>").unwrap();
+
+        xml
+    }
+}
+
+impl Reporter for Junit {
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let rendered = Renderer::plain().render((&message).into()).to_string();
+
+        let mut cases = self.cases.borrow_mut();
+        let case = cases.of(&message);
+
+        if message.level == Level::Error {
+            case.failures.push(Failure {
+                id: message.id.as_deref().map(str::to_owned),
+                title: message.title.to_string(),
+                rendered,
+            });
+        } else {
+            case.output.push_str(&rendered);
+            case.output.push('\n');
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::annotate_snippets::Renderer;
+use eipw_snippets::Message;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::count::Counts;
+use super::{escape_xml, plural, ByOrigin, Error, Reporter};
+
+#[derive(Debug, Default)]
+struct File {
+    counts: Counts,
+    rendered: Vec<String>,
+}
+
+/// Collects messages into a Markdown digest, with summary tables followed by
+/// a collapsible section per file. Suitable for pull request comments and
+/// `$GITHUB_STEP_SUMMARY`.
+#[derive(Debug, Default)]
+pub struct Markdown {
+    lints: RefCell<BTreeMap<String, Counts>>,
+    files: RefCell<ByOrigin<File>>,
+}
+
+impl Markdown {
+    pub fn into_markdown(self) -> String {
+        let lints = self.lints.into_inner();
+        let files = self.files.into_inner();
+
+        let total: Counts = lints.values().copied().sum();
+
+        let mut md = String::new();
+        writeln!(md, "## eipw\n").unwrap();
+
+        if files.is_empty() {
+            writeln!(md, "No problems found.").unwrap();
+            return md;
+        }
+
+        writeln!(
+            md,
+            "Found **{}** and **{}** in **{}**.\n",
+            plural(total.error, "error"),
+            plural(total.warning, "warning"),
+            plural(files.len(), "file"),
+        )
+        .unwrap();
+
+        write_header(&mut md, "Lint");
+        for (slug, counts) in &lints {
+            write_row(&mut md, slug, counts);
+        }
+        md.push('\n');
+
+        write_header(&mut md, "File");
+        for (name, file) in files.iter() {
+            write_row(&mut md, name, &file.counts);
+        }
+
+        for (name, file) in files {
+            writeln!(
+                md,
+                "\n<details>\n<summary><code>{}</code>: {}, {}</summary>\n",
+                escape_xml(&name),
+                plural(file.counts.error, "error"),
+                plural(file.counts.warning, "warning"),
+            )
+            .unwrap();
+
+            let text = file.rendered.join("\n\n");
+            let fence = "`".repeat(longest_run(&text, '`').max(2) + 1);
+            writeln!(md, "{fence}text\n{text}\n{fence}\n\n</details>").unwrap();
+        }
+
+        md
+    }
+}
+
+fn longest_run(text: &str, needle: char) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+
+    for c in text.chars() {
+        if c == needle {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+
+    longest
+}
+
+/// Format `text` as an inline code span that's safe inside a table cell.
+fn code_span(text: &str) -> String {
+    let text = text.replace('|', "\\|");
+    let ticks = "`".repeat(longest_run(&text, '`') + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{ticks} {text} {ticks}")
+    } else {
+        format!("{ticks}{text}{ticks}")
+    }
+}
+
+fn write_header(md: &mut String, kind: &str) {
+    writeln!(md, "| {} | Errors | Warnings | Other |", kind).unwrap();
+    writeln!(md, "| --- | ---: | ---: | ---: |").unwrap();
+}
+
+fn write_row(md: &mut String, name: &str, counts: &Counts) {
+    writeln!(
+        md,
+        "| {} | {} | {} | {} |",
+        code_span(name),
+        counts.error,
+        counts.warning,
+        counts.info + counts.note + counts.help,
+    )
+    .unwrap();
+}
+
+impl Reporter for Markdown {
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let slug = message.id.as_deref().unwrap_or("eipw");
+
+        self.lints
+            .borrow_mut()
+            .entry(slug.to_owned())
+            .or_default()
+            .add(message.level);
+
+        let rendered = Renderer::plain().render((&message).into()).to_string();
+
+        let mut files = self.files.borrow_mut();
+        let file = files.of(&message);
+        file.counts.add(message.level);
+        file.rendered.push(rendered);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::{Level, Message};
+
+use super::{Error, Reporter};
+
+/// Drops messages less severe than a given level.
+#[derive(Debug)]
+pub struct MinLevel<T> {
+    inner: T,
+    level: Level,
+}
+
+impl<T> MinLevel<T> {
+    pub fn new(inner: T, level: Level) -> Self {
+        Self { inner, level }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> Reporter for MinLevel<T>
+where
+    T: Reporter,
+{
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        if message.level < self.level {
+            return Ok(());
+        }
+
+        self.inner.report(message)
+    }
+}
@@ -0,0 +1,46 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::Message;
+
+use std::cell::RefCell;
+use std::io::Write;
+
+use super::json::to_value;
+use super::{Error, Reporter};
+
+/// Writes each message as a single line of JSON as soon as it is reported,
+/// using the same representation as [`super::Json`].
+#[derive(Debug, Default)]
+pub struct Ndjson<W> {
+    inner: RefCell<W>,
+}
+
+impl<W> Reporter for Ndjson<W>
+where
+    W: Write,
+{
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let value = to_value(&message)?;
+
+        let mut inner = self.inner.borrow_mut();
+        serde_json::to_writer(&mut *inner, &value).map_err(Error::new)?;
+        inner.write_all(b"\n").map_err(Error::new)?;
+        inner.flush().map_err(Error::new)
+    }
+}
+
+impl<W> Ndjson<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: inner.into(),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner()
+    }
+}
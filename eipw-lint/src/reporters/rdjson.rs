@@ -0,0 +1,233 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reporters for [reviewdog]'s diagnostic format, either as a single document
+//! ([`Rdjson`]) or as one diagnostic per line ([`Rdjsonl`]).
+//!
+//! [reviewdog]: https://github.com/reviewdog/reviewdog/tree/master/proto/rdf
+
+use eipw_snippets::annotate_snippets::Renderer;
+use eipw_snippets::{Level, Message, Snippet};
+
+use serde::Serialize;
+
+use std::cell::RefCell;
+use std::io::Write;
+
+use super::{primary, Error, Reporter, UNKNOWN_ORIGIN};
+
+#[derive(Debug, Clone, Serialize)]
+struct Source {
+    name: &'static str,
+    url: &'static str,
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Self {
+            name: "eipw",
+            url: "https://github.com/ethereum/eipw",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+#[derive(Debug, Serialize)]
+struct Position {
+    line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+}
+
+impl Position {
+    /// Reviewdog columns count UTF-8 bytes, starting at one.
+    fn of(snippet: &Snippet<'_>, index: usize) -> Self {
+        let position = snippet.position(index);
+        Self {
+            line: position.line,
+            column: Some(position.column_utf8),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Range {
+    start: Position,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<Position>,
+}
+
+impl Range {
+    fn of(snippet: &Snippet<'_>, range: &std::ops::Range<usize>) -> Self {
+        Self {
+            start: Position::of(snippet, range.start),
+            end: Some(Position::of(snippet, range.end)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Location {
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<Range>,
+}
+
+#[derive(Debug, Serialize)]
+struct Code {
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Suggestion {
+    range: Range,
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Diagnostic {
+    message: String,
+    location: Location,
+    severity: Severity,
+    source: Source,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<Code>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<Suggestion>,
+    original_output: String,
+}
+
+impl Diagnostic {
+    fn new(message: &Message<'_>, docs_url: Option<&str>) -> Self {
+        let path = message
+            .snippets
+            .iter()
+            .find_map(|s| s.origin.as_deref())
+            .unwrap_or(UNKNOWN_ORIGIN);
+
+        let range = match primary(message) {
+            Some((snippet, annotation)) => Some(Range::of(snippet, &annotation.range)),
+            None => message.snippets.first().map(|s| Range {
+                start: Position {
+                    line: s.line_start,
+                    column: None,
+                },
+                end: None,
+            }),
+        };
+
+        let severity = match message.level {
+            Level::Error => Severity::Error,
+            Level::Warning => Severity::Warning,
+            Level::Info | Level::Note | Level::Help => Severity::Info,
+        };
+
+        let code = message.id.as_deref().map(|id| Code {
+            value: id.to_owned(),
+            url: docs_url.map(|u| format!("{}{}/", u, id)),
+        });
+
+        let suggestions = message
+            .snippets
+            .iter()
+            .flat_map(|snippet| {
+                snippet.suggestions.iter().map(move |s| Suggestion {
+                    range: Range::of(snippet, &s.range),
+                    text: s.replacement.to_string(),
+                })
+            })
+            .collect();
+
+        Self {
+            message: message.title.to_string(),
+            location: Location {
+                path: path.to_owned(),
+                range,
+            },
+            severity,
+            source: Source::default(),
+            code,
+            suggestions,
+            original_output: Renderer::plain().render(message.into()).to_string(),
+        }
+    }
+}
+
+/// Collects messages into a single reviewdog diagnostic result (`rdjson`.)
+#[derive(Debug, Default, Serialize)]
+pub struct Rdjson {
+    source: Source,
+    diagnostics: RefCell<Vec<Diagnostic>>,
+
+    #[serde(skip)]
+    docs_url: Option<String>,
+}
+
+impl Rdjson {
+    /// Link each lint to its documentation at `{url}{slug}/`.
+    pub fn with_docs_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.docs_url = Some(url.into());
+        self
+    }
+}
+
+impl Reporter for Rdjson {
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let diagnostic = Diagnostic::new(&message, self.docs_url.as_deref());
+        self.diagnostics.borrow_mut().push(diagnostic);
+        Ok(())
+    }
+}
+
+/// Writes each message as a reviewdog diagnostic on its own line (`rdjsonl`)
+/// as soon as it is reported.
+#[derive(Debug, Default)]
+pub struct Rdjsonl<W> {
+    inner: RefCell<W>,
+    docs_url: Option<String>,
+}
+
+impl<W> Rdjsonl<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: inner.into(),
+            docs_url: None,
+        }
+    }
+
+    /// Link each lint to its documentation at `{url}{slug}/`.
+    pub fn with_docs_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.docs_url = Some(url.into());
+        self
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner()
+    }
+}
+
+impl<W> Reporter for Rdjsonl<W>
+where
+    W: Write,
+{
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let diagnostic = Diagnostic::new(&message, self.docs_url.as_deref());
+
+        let mut inner = self.inner.borrow_mut();
+        serde_json::to_writer(&mut *inner, &diagnostic).map_err(Error::new)?;
+        inner.write_all(b"\n").map_err(Error::new)?;
+        inner.flush().map_err(Error::new)
+    }
+}
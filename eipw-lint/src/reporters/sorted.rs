@@ -0,0 +1,62 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::Message;
+
+use std::cell::RefCell;
+
+use super::{Error, Location, Reporter};
+
+/// Buffers messages, then forwards them ordered by origin and line once
+/// [`Sorted::into_inner`] is called.
+///
+/// Messages with the same position keep the order they were reported in.
+#[derive(Debug)]
+pub struct Sorted<T> {
+    inner: T,
+    messages: RefCell<Vec<Message<'static>>>,
+}
+
+impl<T> Sorted<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            messages: Default::default(),
+        }
+    }
+}
+
+impl<T> Sorted<T>
+where
+    T: Reporter,
+{
+    /// Report the buffered messages to the inner reporter, then return it.
+    pub fn into_inner(self) -> Result<T, Error> {
+        let mut messages = self.messages.into_inner();
+
+        messages.sort_by_cached_key(|m| {
+            let location = Location::of(m);
+            (
+                location.origin.map(str::to_owned),
+                location.line,
+                location.column,
+            )
+        });
+
+        for message in messages {
+            self.inner.report(message)?;
+        }
+
+        Ok(self.inner)
+    }
+}
+
+impl<T> Reporter for Sorted<T> {
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        self.messages.borrow_mut().push(message.into_owned());
+        Ok(())
+    }
+}
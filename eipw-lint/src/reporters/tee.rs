@@ -0,0 +1,47 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_snippets::Message;
+
+use super::{Error, Reporter};
+
+/// Sends every message to each of several reporters, in order.
+#[derive(Debug, Default)]
+pub struct Tee<R> {
+    reporters: Vec<R>,
+}
+
+impl<R> Tee<R> {
+    pub fn new(reporters: Vec<R>) -> Self {
+        Self { reporters }
+    }
+
+    pub fn push(&mut self, reporter: R) {
+        self.reporters.push(reporter);
+    }
+
+    pub fn into_inner(self) -> Vec<R> {
+        self.reporters
+    }
+}
+
+impl<R> Reporter for Tee<R>
+where
+    R: Reporter,
+{
+    fn report(&self, message: Message<'_>) -> Result<(), Error> {
+        let (last, rest) = match self.reporters.split_last() {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+
+        for reporter in rest {
+            reporter.report(message.clone())?;
+        }
+
+        last.report(message)
+    }
+}
@@ -15,6 +15,7 @@ use super::{Error, Reporter};
 #[derive(Debug, Default)]
 pub struct Text<W> {
     inner: RefCell<W>,
+    color: bool,
 }
 
 impl<W> Reporter for Text<W>
@@ -22,12 +23,14 @@ where
     W: Write,
 {
     fn report(&self, message: Message<'_>) -> Result<(), Error> {
-        writeln!(
-            self.inner.borrow_mut(),
-            "{}",
-            Renderer::plain().render((&message).into())
-        )
-        .map_err(Error::new)
+        let renderer = if self.color {
+            Renderer::styled()
+        } else {
+            Renderer::plain()
+        };
+
+        let rendered = renderer.render((&message).into()).to_string();
+        writeln!(self.inner.borrow_mut(), "{}", rendered).map_err(Error::new)
     }
 }
 
@@ -35,9 +38,16 @@ impl<W> Text<W> {
     pub fn new(inner: W) -> Self {
         Self {
             inner: inner.into(),
+            color: false,
         }
     }
 
+    /// Render messages with ANSI escape sequences for terminal colors.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
     pub fn into_inner(self) -> W {
         self.inner.into_inner()
     }
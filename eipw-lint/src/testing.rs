@@ -0,0 +1,88 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers shared by the property tests and the fuzz targets. Not part of the
+//! public API.
+
+use eipw_snippets::Message;
+
+use crate::lints::{Context, Error, Lint};
+use crate::reporters::{self, Reporter};
+use crate::Linter;
+
+/// Panics if any annotation or suggestion in `message` doesn't fall on character boundaries.
+pub fn check_message(message: &Message<'_>) {
+    for snippet in &message.snippets {
+        let ranges = snippet
+            .annotations
+            .iter()
+            .map(|a| &a.range)
+            .chain(snippet.suggestions.iter().map(|s| &s.range));
+
+        for range in ranges {
+            let source = &snippet.source;
+            assert!(range.start <= range.end, "{:?} in {:?}", range, source);
+            assert!(range.end <= source.len(), "{:?} in {:?}", range, source);
+            assert!(
+                source.is_char_boundary(range.start),
+                "{:?} in {:?}",
+                range,
+                source
+            );
+            assert!(
+                source.is_char_boundary(range.end),
+                "{:?} in {:?}",
+                range,
+                source
+            );
+        }
+    }
+
+    message.footer.iter().for_each(check_message);
+}
+
+/// Checks every reported message with [`check_message`].
+#[derive(Debug, Default)]
+pub struct CheckBoundaries;
+
+impl Reporter for CheckBoundaries {
+    fn report(&self, message: Message<'_>) -> Result<(), reporters::Error> {
+        check_message(&message);
+        Ok(())
+    }
+}
+
+/// Builds a snippet for every node in the body.
+#[derive(Debug)]
+pub struct EveryNode;
+
+impl Lint for EveryNode {
+    fn lint<'a>(&self, slug: &'a str, ctx: &Context<'a, '_>) -> Result<(), Error> {
+        for node in ctx.body().descendants() {
+            let ast = node.data.borrow();
+            ctx.ast_source(&ast);
+            ctx.ast_lines(&ast);
+            ctx.report(
+                ctx.annotation_level()
+                    .title("node")
+                    .id(slug)
+                    .snippet(ctx.ast_snippet(&ast, None, "here")),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs `linter` to completion, ignoring any error it returns.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run<R: Reporter>(linter: Linter<'_, R>) {
+    // Errors (like slices referencing other proposals) are fine, panics aren't.
+    let _ = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(linter.run());
+}
@@ -0,0 +1,107 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Wall time spent in each phase of a [`Linter`](crate::Linter) run.
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// Time spent in a single lint, summed across all files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LintTimings {
+    pub prepare: Duration,
+    pub find_resources: Duration,
+    pub lint: Duration,
+}
+
+impl LintTimings {
+    pub fn total(&self) -> Duration {
+        self.prepare + self.find_resources + self.lint
+    }
+}
+
+/// Collects timings from a linter run.
+///
+/// Pass one to [`Linter::record_timings`](crate::Linter::record_timings)
+/// before running. Nothing is measured otherwise.
+#[derive(Debug, Default)]
+pub struct Timings {
+    fetch: Cell<Duration>,
+    parse: Cell<Duration>,
+    lints: RefCell<BTreeMap<String, LintTimings>>,
+}
+
+impl Timings {
+    /// Time spent reading sources and the proposals they reference.
+    pub fn fetch(&self) -> Duration {
+        self.fetch.get()
+    }
+
+    /// Time spent splitting and parsing preambles and markdown bodies.
+    pub fn parse(&self) -> Duration {
+        self.parse.get()
+    }
+
+    pub fn lints(&self) -> BTreeMap<String, LintTimings> {
+        self.lints.borrow().clone()
+    }
+
+    fn lint_mut<F>(&self, slug: &str, f: F)
+    where
+        F: FnOnce(&mut LintTimings),
+    {
+        let mut lints = self.lints.borrow_mut();
+        match lints.get_mut(slug) {
+            Some(timings) => f(timings),
+            None => f(lints.entry(slug.to_owned()).or_default()),
+        }
+    }
+}
+
+/// Measures a single step, but only when timings are being recorded, so
+/// [`Instant`] is never touched otherwise (it panics on some wasm targets.)
+pub(crate) struct Stopwatch<'a>(Option<(&'a Timings, Instant)>);
+
+impl<'a> Stopwatch<'a> {
+    pub(crate) fn start(timings: Option<&'a Timings>) -> Self {
+        Self(timings.map(|t| (t, Instant::now())))
+    }
+
+    pub(crate) fn fetch(self) {
+        if let Some((timings, started)) = self.0 {
+            timings.fetch.set(timings.fetch.get() + started.elapsed());
+        }
+    }
+
+    pub(crate) fn parse(self) {
+        if let Some((timings, started)) = self.0 {
+            timings.parse.set(timings.parse.get() + started.elapsed());
+        }
+    }
+
+    pub(crate) fn prepare(self, slug: &str) {
+        if let Some((timings, started)) = self.0 {
+            let elapsed = started.elapsed();
+            timings.lint_mut(slug, |t| t.prepare += elapsed);
+        }
+    }
+
+    pub(crate) fn find_resources(self, slug: &str) {
+        if let Some((timings, started)) = self.0 {
+            let elapsed = started.elapsed();
+            timings.lint_mut(slug, |t| t.find_resources += elapsed);
+        }
+    }
+
+    pub(crate) fn lint(self, slug: &str) {
+        if let Some((timings, started)) = self.0 {
+            let elapsed = started.elapsed();
+            timings.lint_mut(slug, |t| t.lint += elapsed);
+        }
+    }
+}
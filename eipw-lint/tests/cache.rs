@@ -0,0 +1,171 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use chrono::NaiveDate;
+
+use eipw_lint::cache::Cache;
+use eipw_lint::lints::preamble::Trim;
+use eipw_lint::lints::{Context, Error, Lint};
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const SRC: &str = r#"---
+header:value0
+---
+hello world"#;
+
+const EXPECTED: &str = r#"error[preamble-trim]: preamble header values must begin with a space
+ --> first.md:2:8
+  |
+2 | header:value0
+  |        ^ space required here
+  |
+"#;
+
+#[derive(Debug)]
+struct Counted(&'static AtomicUsize);
+
+impl Lint for Counted {
+    fn lint<'a>(&self, _slug: &'a str, _ctx: &Context<'a, '_>) -> Result<(), Error> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+async fn run(cache: &Cache, src: &str, runs: &'static AtomicUsize) -> String {
+    Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("counted", Counted(runs))
+        .deny("preamble-trim", Trim)
+        .use_cache(cache)
+        .check_slice(Some("first.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner()
+}
+
+#[tokio::test]
+async fn reused() {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+    let cache = Cache::new(&"config").unwrap();
+
+    assert_eq!(run(&cache, SRC, &RUNS).await, EXPECTED);
+    assert_eq!(run(&cache, SRC, &RUNS).await, EXPECTED);
+
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    assert_eq!((cache.hits(), cache.misses()), (1, 1));
+}
+
+#[tokio::test]
+async fn changed_source() {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+    let cache = Cache::new(&"config").unwrap();
+
+    let fixed = SRC.replace("header:", "header: ");
+
+    assert_eq!(run(&cache, SRC, &RUNS).await, EXPECTED);
+    assert_eq!(run(&cache, &fixed, &RUNS).await, "");
+
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+    assert_eq!((cache.hits(), cache.misses()), (0, 2));
+}
+
+#[tokio::test]
+async fn save_and_load() {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    let first = Cache::new(&"config").unwrap();
+    run(&first, SRC, &RUNS).await;
+
+    let mut saved = Vec::new();
+    first.save(&mut saved).unwrap();
+
+    let mut same = Cache::new(&"config").unwrap();
+    same.load(saved.as_slice()).unwrap();
+    assert_eq!(run(&same, SRC, &RUNS).await, EXPECTED);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    let mut other = Cache::new(&"other config").unwrap();
+    other.load(saved.as_slice()).unwrap();
+    assert_eq!(run(&other, SRC, &RUNS).await, EXPECTED);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn changed_date() {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut first = Cache::new(&"config").unwrap();
+    first.set_today(NaiveDate::from_ymd_opt(2023, 3, 1).unwrap());
+    run(&first, SRC, &RUNS).await;
+
+    let mut saved = Vec::new();
+    first.save(&mut saved).unwrap();
+
+    let mut same = Cache::new(&"config").unwrap();
+    same.set_today(NaiveDate::from_ymd_opt(2023, 3, 1).unwrap());
+    same.load(saved.as_slice()).unwrap();
+    assert_eq!(run(&same, SRC, &RUNS).await, EXPECTED);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    let mut tomorrow = Cache::new(&"config").unwrap();
+    tomorrow.set_today(NaiveDate::from_ymd_opt(2023, 3, 2).unwrap());
+    tomorrow.load(saved.as_slice()).unwrap();
+    assert_eq!(run(&tomorrow, SRC, &RUNS).await, EXPECTED);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+    assert_eq!((tomorrow.hits(), tomorrow.misses()), (0, 1));
+}
+
+#[tokio::test]
+async fn save_keeps_unused() {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    let first = Cache::new(&"config").unwrap();
+    run(&first, SRC, &RUNS).await;
+
+    let mut saved = Vec::new();
+    first.save(&mut saved).unwrap();
+
+    let mut second = Cache::new(&"config").unwrap();
+    second.load(saved.as_slice()).unwrap();
+    run(&second, &SRC.replace("value0", "value1"), &RUNS).await;
+
+    let mut resaved = Vec::new();
+    second.save(&mut resaved).unwrap();
+
+    let mut third = Cache::new(&"config").unwrap();
+    third.load(resaved.as_slice()).unwrap();
+    assert_eq!(run(&third, SRC, &RUNS).await, EXPECTED);
+
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+    assert_eq!((third.hits(), third.misses()), (1, 0));
+}
+
+#[tokio::test]
+async fn save_drops_stale() {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut first = Cache::new(&"config").unwrap();
+    first.set_today(NaiveDate::from_ymd_opt(2023, 3, 1).unwrap());
+    run(&first, SRC, &RUNS).await;
+
+    let mut saved = Vec::new();
+    first.save(&mut saved).unwrap();
+
+    let mut second = Cache::new(&"config").unwrap();
+    second.set_today(NaiveDate::from_ymd_opt(2023, 3, 2).unwrap());
+    second.load(saved.as_slice()).unwrap();
+
+    let mut resaved = Vec::new();
+    second.save(&mut resaved).unwrap();
+
+    assert!(String::from_utf8(saved).unwrap().contains("2023-03-01"));
+    assert_eq!(String::from_utf8(resaved).unwrap(), r#"{"entries":{}}"#);
+}
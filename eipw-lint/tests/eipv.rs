@@ -7,6 +7,7 @@
 #![cfg(feature = "tokio")]
 #![cfg(not(target_arch = "wasm32"))]
 
+use eipw_lint::lints::preamble::Timeline;
 use eipw_lint::reporters::Text;
 use eipw_lint::Linter;
 
@@ -58,6 +59,17 @@ async fn eipv() -> std::io::Result<()> {
         println!("Testing {}...", entry.path().display());
         let reports = Linter::<Text<String>>::default()
             .allow("preamble-file-name")
+            .deny(
+                "preamble-timeline",
+                // Not on by default; checked here against a fixed date.
+                Timeline {
+                    created: "created",
+                    deadline: "last-call-deadline",
+                    status: "status",
+                    deadline_status: "Last Call",
+                    today: Some("2020-01-01"),
+                },
+            )
             .check_file(input_path)
             .run()
             .await
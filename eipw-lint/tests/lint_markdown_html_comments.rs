@@ -120,3 +120,33 @@ hello <!-- inline --> text
 "#
     );
 }
+
+#[tokio::test]
+async fn spell_directive() {
+    let src = r#"---
+header: value2
+---
+hello
+
+<!-- eipw-spell: wordA wordB -->
+
+text <!-- eipw-spell: wordC --> after
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-html-comments",
+            HtmlComments {
+                name: "header",
+                warn_for: vec!["value1"],
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
@@ -5,10 +5,12 @@
  */
 
 use eipw_lint::lints::markdown::Spell;
-use eipw_lint::reporters::Text;
+use eipw_lint::reporters::{Json, Text};
 use eipw_lint::Linter;
 use pretty_assertions::assert_eq;
 
+use serde_json::json;
+
 #[tokio::test]
 async fn correctly_spelled() {
     let src = r#"---
@@ -65,10 +67,42 @@ Here is ssome text.
 5 | Here is ssome text.
   |         ^^^^^ incorrectly spelled
   |
+  = help: did you mean `some`?
 "#
     );
 }
 
+#[tokio::test]
+async fn swapped_letters_suggested_first() {
+    let src = r#"---
+header: value1
+---
+
+Teh text.
+"#;
+
+    let reports = Linter::new(Json::default())
+        .clear_lints()
+        .deny(
+            "markdown-spell",
+            Spell {
+                personal_dictionary: "",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_reports();
+
+    let footer = reports[0]["footer"][0]["title"].as_str().unwrap();
+    assert!(
+        footer.starts_with("did you mean one of: `The`,"),
+        "{}",
+        footer
+    );
+}
+
 #[tokio::test]
 async fn incorrectly_spelled_in_code() {
     let src = r#"---
@@ -176,11 +210,259 @@ header: value1
         reports,
         r#"error[markdown-spell]: the word `helloworld` is misspelled
   |
-4 | /
-5 | | **hello**world
-  | |______________^ somewhere here
+5 | **hello**world
+  |   ^^^^^^^^^^^^ incorrectly spelled
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn several_personal_dictionaries() {
+    let first = r#"---
+header: value1
+---
+
+Here is ssome text.
+"#;
+
+    let second = r#"---
+header: value1
+---
+
+Here is sssome text.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-spell-ssome",
+            Spell {
+                personal_dictionary: "ssome",
+            },
+        )
+        .deny(
+            "markdown-spell-sssome",
+            Spell {
+                personal_dictionary: "sssome",
+            },
+        )
+        .check_slice(None, first)
+        .check_slice(None, second)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-spell-sssome]: the word `ssome` is misspelled
+  |
+5 | Here is ssome text.
+  |         ^^^^^ incorrectly spelled
+  |
+  = help: did you mean one of: `some`, `sssome`?
+error[markdown-spell-ssome]: the word `sssome` is misspelled
+  |
+5 | Here is sssome text.
+  |         ^^^^^^ incorrectly spelled
+  |
+  = help: did you mean `ssome`?
+"#
+    );
+}
+
+#[tokio::test]
+async fn allowed_by_directive() {
+    let src = r#"---
+header: value1
+---
+
+<!-- eipw-spell: ssome Sssome -->
+
+Here is ssome text, and SSOME more, and <!-- eipw-spell: tttext --> tttext.
+
+Sssome text.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-spell",
+            Spell {
+                personal_dictionary: "",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn directive_only_applies_to_its_file() {
+    let first = r#"---
+header: value1
+---
+
+<!-- eipw-spell: ssome -->
+
+Here is ssome text.
+"#;
+
+    let second = r#"---
+header: value1
+---
+
+Here is ssome text.
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-spell",
+            Spell {
+                personal_dictionary: "",
+            },
+        )
+        .check_slice(None, first)
+        .check_slice(None, second)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-spell]: the word `ssome` is misspelled
+  |
+5 | Here is ssome text.
+  |         ^^^^^ incorrectly spelled
+  |
+  = help: did you mean `some`?
+"#
+    );
+}
+
+#[tokio::test]
+async fn fix_for_single_suggestion() {
+    let src = r#"---
+header: value1
+---
+
+Here is ssome text, and a mispelled word.
+"#;
+
+    let reports = Linter::new(Json::default())
+        .clear_lints()
+        .deny(
+            "markdown-spell",
+            Spell {
+                personal_dictionary: "",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_reports();
+
+    let mut fixes: Vec<_> = reports
+        .iter()
+        .map(|r| (r["title"].clone(), r["snippets"][0]["suggestions"].clone()))
+        .collect();
+    fixes.sort_by_key(|(title, _)| title.to_string());
+
+    assert_eq!(
+        fixes,
+        [
+            (json!("the word `mispelled` is misspelled"), json!(null)),
+            (
+                json!("the word `ssome` is misspelled"),
+                json!([{
+                    "range": { "start": 8, "end": 13 },
+                    "replacement": "some",
+                    "span": {
+                        "start": { "line": 5, "column_utf8": 9, "column_utf16": 9, "column_chars": 9 },
+                        "end": { "line": 5, "column_utf8": 14, "column_utf16": 14, "column_chars": 14 },
+                    },
+                }]),
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn every_occurrence() {
+    let src = r#"---
+header: value1
+---
+
+Ssome text &amp; ssome more, and \*ssome\*
+across ssome lines.
+
+- a *ssome* item
+
+| Heading |
+|---------|
+| ssome   |
+"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "markdown-spell",
+            Spell {
+                personal_dictionary: "",
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[markdown-spell]: the word `Ssome` is misspelled
+  |
+5 | Ssome text &amp; ssome more, and \*ssome\*
+  | ^^^^^ incorrectly spelled
+  |
+  = help: did you mean `Some`?
+error[markdown-spell]: the word `ssome` is misspelled
+  |
+5 | Ssome text &amp; ssome more, and \*ssome\*
+  |                  ^^^^^ incorrectly spelled
+  |
+  = help: did you mean `some`?
+error[markdown-spell]: the word `ssome` is misspelled
+  |
+5 | Ssome text &amp; ssome more, and \*ssome\*
+  |                                    ^^^^^ incorrectly spelled
+  |
+  = help: did you mean `some`?
+error[markdown-spell]: the word `ssome` is misspelled
+  |
+6 | across ssome lines.
+  |        ^^^^^ incorrectly spelled
+  |
+  = help: did you mean `some`?
+error[markdown-spell]: the word `ssome` is misspelled
+  |
+8 | - a *ssome* item
+  |      ^^^^^ incorrectly spelled
   |
-  = warning: could not find a line number for this message
+  = help: did you mean `some`?
+error[markdown-spell]: the word `ssome` is misspelled
+   |
+12 | | ssome   |
+   |   ^^^^^ incorrectly spelled
+   |
+   = help: did you mean `some`?
 "#
     );
 }
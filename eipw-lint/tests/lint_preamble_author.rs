@@ -27,10 +27,10 @@ hello world"#;
 
     assert_eq!(
         reports,
-        r#"error[preamble-author]: authors in the preamble must match the expected format
+        r#"error[preamble-author]: author GitHub username is missing a closing `)`
   |
 2 | header: Bánana (
-  |        ^^^^^^^^^ unrecognized author
+  |                ^ missing closing `)`
   |
   = help: Try `Random J. User (@username) <test@example.com>` for an author with a GitHub username plus email.
   = help: Try `Random J. User (@username)` for an author with a GitHub username.
@@ -62,10 +62,10 @@ hello world"#;
 
     assert_eq!(
         reports,
-        r#"error[preamble-author]: authors in the preamble must match the expected format
+        r#"error[preamble-author]: author GitHub username is missing a closing `)`
   |
 2 | header: Foo (
-  |        ^^^^^^ unrecognized author
+  |             ^ missing closing `)`
   |
   = help: Try `Random J. User (@username) <test@example.com>` for an author with a GitHub username plus email.
   = help: Try `Random J. User (@username)` for an author with a GitHub username.
@@ -97,10 +97,10 @@ hello world"#;
 
     assert_eq!(
         reports,
-        r#"error[preamble-author]: authors in the preamble must match the expected format
+        r#"error[preamble-author]: author GitHub username is missing a closing `)`
   |
 2 | header: User (@user), Foo (
-  |                      ^^^^^^ unrecognized author
+  |                           ^ missing closing `)`
   |
   = help: Try `Random J. User (@username) <test@example.com>` for an author with a GitHub username plus email.
   = help: Try `Random J. User (@username)` for an author with a GitHub username.
@@ -128,10 +128,10 @@ hello world"#;
 
     assert_eq!(
         reports,
-        r#"error[preamble-author]: authors in the preamble must match the expected format
+        r#"error[preamble-author]: author GitHub username is missing a closing `)`
   |
 2 | header: Foo (, User (@user)
-  |        ^^^^^^ unrecognized author
+  |             ^ missing closing `)`
   |
   = help: Try `Random J. User (@username) <test@example.com>` for an author with a GitHub username plus email.
   = help: Try `Random J. User (@username)` for an author with a GitHub username.
@@ -167,6 +167,25 @@ hello world"#;
     );
 }
 
+#[tokio::test]
+async fn empty_items() {
+    let src = r#"---
+header: Foo (@foo), , Bar (@bar),
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-author", Author("header"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
 #[tokio::test]
 async fn valid() {
     let src = r#"---
@@ -204,3 +223,92 @@ hello world"#;
 
     assert_eq!(reports, "");
 }
+
+#[tokio::test]
+async fn valid_quoted() {
+    let src = r#"---
+header: "User, Random J." (@user) <"random,user"@example.com>, Bar
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-author", Author("header"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn missing_space() {
+    let src = r#"---
+header: Foo(@foo)
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-author", Author("header"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-author]: author contact information must be preceded by a space
+  |
+2 | header: Foo(@foo)
+  |            ^ add a space before this
+  |
+  = help: Try `Random J. User (@username) <test@example.com>` for an author with a GitHub username plus email.
+  = help: Try `Random J. User (@username)` for an author with a GitHub username.
+  = help: Try `Random J. User <test@example.com>` for an author with an email.
+  = help: Try `Random J. User` for an author without contact information.
+error[preamble-author]: preamble header `header` must contain at least one GitHub username
+  |
+2 | header: Foo(@foo)
+  |
+"#,
+    );
+}
+
+#[tokio::test]
+async fn invalid_email() {
+    let src = r#"---
+header: Foo (@foo) <foo@example>
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-author", Author("header"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-author]: author email address is invalid
+  |
+2 | header: Foo (@foo) <foo@example>
+  |                    ^^^^^^^^^^^^^ not a valid email address
+  |
+  = help: Try `Random J. User (@username) <test@example.com>` for an author with a GitHub username plus email.
+  = help: Try `Random J. User (@username)` for an author with a GitHub username.
+  = help: Try `Random J. User <test@example.com>` for an author with an email.
+  = help: Try `Random J. User` for an author without contact information.
+error[preamble-author]: preamble header `header` must contain at least one GitHub username
+  |
+2 | header: Foo (@foo) <foo@example>
+  |
+"#,
+    );
+}
@@ -0,0 +1,265 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::condition::{Predicate, Rule};
+use eipw_lint::lints::preamble::Condition;
+use eipw_lint::lints::DefaultLint;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+fn in_last_call() -> Condition<&'static str> {
+    Condition {
+        name: "last-call-deadline",
+        rule: Rule::ForbiddenUnless,
+        when: Predicate::OneOf {
+            name: "status",
+            values: vec!["Last Call"],
+        },
+    }
+}
+
+#[tokio::test]
+async fn forbidden_unless_stale() {
+    let src = r#"---
+status: Final
+last-call-deadline: 2023-01-01
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-condition", in_last_call())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-condition]: preamble header `last-call-deadline` is only allowed when `status` is `Last Call`
+  |
+2 | status: Final
+  | ------------- info: defined here
+  |
+3 | last-call-deadline: 2023-01-01
+  | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remove this
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn forbidden_unless_satisfied() {
+    let src = r#"---
+status: Last Call
+last-call-deadline: 2023-01-01
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-condition", in_last_call())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn forbidden_unless_absent() {
+    let src = r#"---
+status: Final
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-condition", in_last_call())
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn forbidden_if_one_of() {
+    let src = r#"---
+status: Stagnant
+withdrawal-reason: nope
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "preamble-condition",
+            Condition {
+                name: "withdrawal-reason",
+                rule: Rule::ForbiddenIf,
+                when: Predicate::OneOf {
+                    name: "status",
+                    values: vec!["Draft", "Stagnant"],
+                },
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-condition]: preamble header `withdrawal-reason` is not allowed when `status` is one of `Draft`, `Stagnant`
+  |
+2 | status: Stagnant
+  | ---------------- info: defined here
+  |
+3 | withdrawal-reason: nope
+  | ^^^^^^^^^^^^^^^^^^^^^^^ remove this
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn required_if_all() {
+    let src = r#"---
+type: Standards Track
+category: Core
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "preamble-condition",
+            Condition {
+                name: "requires",
+                rule: Rule::RequiredIf,
+                when: Predicate::All(vec![
+                    Predicate::OneOf {
+                        name: "type",
+                        values: vec!["Standards Track"],
+                    },
+                    Predicate::Not(Box::new(Predicate::OneOf {
+                        name: "category",
+                        values: vec!["ERC", "Interface"],
+                    })),
+                ]),
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-condition]: preamble header `requires` is required when `type` is `Standards Track` and `category` is not one of `ERC`, `Interface`
+  |
+2 | type: Standards Track
+  | --------------------- info: defined here
+  |
+3 | category: Core
+  | -------------- info: defined here
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn required_unless_any() {
+    let src = r#"---
+header: value
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "preamble-condition",
+            Condition {
+                name: "discussions-to",
+                rule: Rule::RequiredUnless,
+                when: Predicate::Any(vec![
+                    Predicate::Present("withdrawal-reason"),
+                    Predicate::All(vec![
+                        Predicate::Present("status"),
+                        Predicate::Present("type"),
+                    ]),
+                ]),
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-condition]: preamble header `discussions-to` is required unless `withdrawal-reason` is present or (`status` is present and `type` is present)
+ |
+ |
+"#
+    );
+}
+
+#[tokio::test]
+async fn required_unless_satisfied() {
+    let src = r#"---
+withdrawal-reason: value
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny(
+            "preamble-condition",
+            Condition {
+                name: "discussions-to",
+                rule: Rule::RequiredUnless,
+                when: Predicate::Present("withdrawal-reason"),
+            },
+        )
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[test]
+fn deserialize() {
+    let input = r#"
+kind = "preamble-condition"
+name = "last-call-deadline"
+rule = "forbidden-unless"
+when = { one-of = { name = "status", values = ["Last Call"] } }
+"#;
+
+    let lint: DefaultLint<String> = toml::from_str(input).unwrap();
+    assert!(matches!(
+        lint,
+        DefaultLint::PreambleCondition(Condition {
+            rule: Rule::ForbiddenUnless,
+            when: Predicate::OneOf { .. },
+            ..
+        })
+    ));
+}
@@ -0,0 +1,213 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::Timeline;
+use eipw_lint::lints::DefaultLint;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+fn timeline(today: &'static str) -> Timeline<&'static str> {
+    Timeline {
+        created: "created",
+        deadline: "last-call-deadline",
+        status: "status",
+        deadline_status: "Last Call",
+        today: Some(today),
+    }
+}
+
+#[tokio::test]
+async fn created_in_future() {
+    let src = r#"---
+status: Draft
+created: 2023-06-02
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-timeline", timeline("2023-06-01"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-timeline]: preamble header `created` is in the future
+  |
+3 | created: 2023-06-02
+  |         ^^^^^^^^^^^ after today (2023-06-01)
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn deadline_before_created() {
+    let src = r#"---
+status: Last Call
+last-call-deadline: 2023-01-01
+created: 2023-01-02
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-timeline", timeline("2022-12-01"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-timeline]: preamble header `created` is in the future
+  |
+4 | created: 2023-01-02
+  |         ^^^^^^^^^^^ after today (2022-12-01)
+  |
+error[preamble-timeline]: preamble header `last-call-deadline` must be after `created`
+  |
+3 | last-call-deadline: 2023-01-01
+  |                    ^^^^^^^^^^^ not after creation
+  |
+4 | created: 2023-01-02
+  |         ----------- info: created here
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn deadline_passed() {
+    let src = r#"---
+status: Last Call
+last-call-deadline: 2023-02-01
+created: 2023-01-01
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-timeline", timeline("2023-03-01"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        reports,
+        r#"error[preamble-timeline]: preamble header `last-call-deadline` has passed while `status` is `Last Call`
+  |
+3 | last-call-deadline: 2023-02-01
+  |                    ^^^^^^^^^^^ before today (2023-03-01)
+  |
+  = help: extend the deadline or update the status
+"#
+    );
+}
+
+#[tokio::test]
+async fn deadline_passed_other_status() {
+    let src = r#"---
+status: Final
+last-call-deadline: 2023-02-01
+created: 2023-01-01
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-timeline", timeline("2023-03-01"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn valid() {
+    let src = r#"---
+status: Last Call
+last-call-deadline: 2023-02-01
+created: 2023-01-01
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-timeline", timeline("2023-02-01"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn unparseable_dates_ignored() {
+    let src = r#"---
+status: Last Call
+last-call-deadline: soon
+created: 3000-01-01T00:00
+---
+hello world"#;
+
+    let reports = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-timeline", timeline("2023-02-01"))
+        .check_slice(None, src)
+        .run()
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(reports, "");
+}
+
+#[tokio::test]
+async fn invalid_today() {
+    let src = r#"---
+created: 2023-01-01
+---
+hello world"#;
+
+    let result = Linter::<Text<String>>::default()
+        .clear_lints()
+        .deny("preamble-timeline", timeline("yesterday"))
+        .check_slice(None, src)
+        .run()
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn deserialize() {
+    let input = r#"
+kind = "preamble-timeline"
+created = "created"
+deadline = "last-call-deadline"
+status = "status"
+deadline-status = "Last Call"
+"#;
+
+    let lint: DefaultLint<String> = toml::from_str(input).unwrap();
+    assert!(matches!(
+        lint,
+        DefaultLint::PreambleTimeline(Timeline { today: None, .. })
+    ));
+}
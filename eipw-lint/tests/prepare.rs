@@ -0,0 +1,84 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use assert_matches::assert_matches;
+
+use eipw_lint::lints::markdown::regex::{Mode, Regex};
+use eipw_lint::lints::{Context, Error, Lint};
+use eipw_lint::reporters::Null;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const SRC: &str = r#"---
+header: value0
+---
+hello world"#;
+
+static PREPARED: AtomicUsize = AtomicUsize::new(0);
+static UNPREPARED_RUNS: AtomicUsize = AtomicUsize::new(0);
+static PREPARED_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug)]
+struct Unprepared;
+
+impl Lint for Unprepared {
+    fn prepare(&self) -> Result<Option<Box<dyn Lint>>, Error> {
+        PREPARED.fetch_add(1, Ordering::SeqCst);
+        Ok(Some(Box::new(Prepared)))
+    }
+
+    fn lint<'a>(&self, _slug: &'a str, _ctx: &Context<'a, '_>) -> Result<(), Error> {
+        UNPREPARED_RUNS.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Prepared;
+
+impl Lint for Prepared {
+    fn lint<'a>(&self, _slug: &'a str, _ctx: &Context<'a, '_>) -> Result<(), Error> {
+        PREPARED_RUNS.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn prepared_once() {
+    Linter::new(Null)
+        .clear_lints()
+        .deny("unprepared", Unprepared)
+        .check_slice(Some("first.md"), SRC)
+        .check_slice(Some("second.md"), SRC)
+        .run()
+        .await
+        .unwrap();
+
+    assert_eq!(PREPARED.load(Ordering::SeqCst), 1);
+    assert_eq!(UNPREPARED_RUNS.load(Ordering::SeqCst), 0);
+    assert_eq!(PREPARED_RUNS.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn invalid_pattern() {
+    let result = Linter::new(Null)
+        .clear_lints()
+        .deny(
+            "markdown-re",
+            Regex {
+                mode: Mode::Excludes,
+                pattern: "(",
+                message: "unbalanced",
+            },
+        )
+        .check_slice(Some("first.md"), SRC)
+        .run()
+        .await;
+
+    assert_matches!(result, Err(eipw_lint::Error::Lint { origin: None, .. }));
+}
@@ -0,0 +1,78 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::testing::{run, CheckBoundaries, EveryNode};
+use eipw_lint::Linter;
+
+use proptest::prelude::*;
+
+fn fragment() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("\n".to_string()),
+        Just("\n\n".to_string()),
+        Just("\r\n".to_string()),
+        Just("\t".to_string()),
+        Just("# Heading\n".to_string()),
+        Just("## Specification\n".to_string()),
+        Just("*\u{e1}emph\u{e1}*".to_string()),
+        Just("**strong**".to_string()),
+        Just("`code`".to_string()),
+        Just("[link](https://example.com)".to_string()),
+        Just("[link](./eip-1.md)".to_string()),
+        Just("<https://example.com>".to_string()),
+        Just("foo@example.com".to_string()),
+        Just("EIP-1".to_string()),
+        Just("```\ncode\nblock\n```\n".to_string()),
+        Just("| a | b |\n|---|---|\n| \u{1F600} | d |\n".to_string()),
+        Just("<!-- comment -->".to_string()),
+        Just("<div>html</div>".to_string()),
+        Just("[^1]".to_string()),
+        Just("[^1]: note\n".to_string()),
+        Just("> quote\n".to_string()),
+        Just("- item\n".to_string()),
+        Just("1. item\n".to_string()),
+        Just("$$ math $$".to_string()),
+        Just("\u{201C}smart\u{201D}".to_string()),
+        "\\PC{0,12}",
+    ]
+}
+
+fn document() -> impl Strategy<Value = String> {
+    prop::collection::vec(fragment(), 0..24).prop_map(|body| {
+        format!(
+            "---\n\
+            eip: 1234\n\
+            title: A t\u{ed}tle\n\
+            description: A description\n\
+            author: J\u{e1}ne Doe (@jane)\n\
+            discussions-to: https://ethereum-magicians.org/t/x/1\n\
+            status: Draft\n\
+            type: Meta\n\
+            created: 2020-01-01\n\
+            ---\n{}",
+            body.concat()
+        )
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn default_lints(src in document()) {
+        run(Linter::new(CheckBoundaries).check_slice(None, &src));
+    }
+
+    #[test]
+    fn ast_snippet(src in document()) {
+        run(
+            Linter::new(CheckBoundaries)
+                .clear_lints()
+                .deny("every-node", EveryNode)
+                .check_slice(None, &src),
+        );
+    }
+}
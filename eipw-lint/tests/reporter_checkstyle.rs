@@ -0,0 +1,42 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Author, Trim};
+use eipw_lint::reporters::Checkstyle;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn errors_and_warnings() {
+    let src = r#"---
+author: Fóo <foo@example
+header:  value
+---
+hello world"#;
+
+    let reports = Linter::new(Checkstyle::default())
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .into_xml();
+
+    assert_eq!(
+        reports,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<checkstyle version="4.3">
+  <file name="eip-1.md">
+    <error line="2" column="13" severity="error" message="author email address is missing a closing `&gt;`" source="eipw.preamble-author"/>
+    <error line="2" severity="error" message="preamble header `author` must contain at least one GitHub username" source="eipw.preamble-author"/>
+    <error line="3" column="8" severity="warning" message="preamble header `header` has extra whitespace" source="eipw.preamble-trim"/>
+  </file>
+</checkstyle>
+"#
+    );
+}
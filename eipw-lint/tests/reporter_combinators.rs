@@ -0,0 +1,135 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Author, Trim};
+use eipw_lint::reporters::{Except, Json, MinLevel, Only, Reporter, Sorted, Tee};
+use eipw_lint::Linter;
+use eipw_snippets::Level;
+use pretty_assertions::assert_eq;
+
+const SRC: &str = r#"---
+author: Foo <foo@example
+header:  value
+---
+hello world"#;
+
+const OTHER: &str = r#"---
+header:  value
+---
+hello world"#;
+
+async fn run<R: Reporter>(reporter: R) -> R {
+    Linter::new(reporter)
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-2.md"), OTHER)
+        .check_slice(Some("eip-1.md"), SRC)
+        .run()
+        .await
+        .unwrap()
+}
+
+fn summarize(json: Json) -> Vec<(String, String)> {
+    json.into_reports()
+        .into_iter()
+        .map(|r| {
+            let origin = r["snippets"][0]["origin"].as_str().unwrap().to_owned();
+            let id = r["id"].as_str().unwrap().to_owned();
+            (origin, id)
+        })
+        .collect()
+}
+
+fn pair(origin: &str, id: &str) -> (String, String) {
+    (origin.to_owned(), id.to_owned())
+}
+
+#[tokio::test]
+async fn tee() {
+    let reporters = run(Tee::new(vec![Json::default(), Json::default()]))
+        .await
+        .into_inner();
+
+    assert_eq!(reporters.len(), 2);
+
+    let mut reporters = reporters.into_iter().map(summarize);
+    let first = reporters.next().unwrap();
+    assert_eq!(first.len(), 4);
+    assert_eq!(Some(first), reporters.next());
+}
+
+#[tokio::test]
+async fn min_level() {
+    let errors = run(MinLevel::new(Json::default(), Level::Error))
+        .await
+        .into_inner();
+    assert_eq!(
+        summarize(errors),
+        [
+            pair("eip-1.md", "preamble-author"),
+            pair("eip-1.md", "preamble-author"),
+        ]
+    );
+
+    let all = run(MinLevel::new(Json::default(), Level::Warning))
+        .await
+        .into_inner();
+    assert_eq!(summarize(all).len(), 4);
+}
+
+#[tokio::test]
+async fn only_and_except() {
+    let only = run(Only::new(Json::default(), ["preamble-trim"]))
+        .await
+        .into_inner();
+    assert_eq!(
+        summarize(only),
+        [
+            pair("eip-2.md", "preamble-trim"),
+            pair("eip-1.md", "preamble-trim"),
+        ]
+    );
+
+    let except = run(Except::new(Json::default(), ["preamble-trim"]))
+        .await
+        .into_inner();
+    assert_eq!(
+        summarize(except),
+        [
+            pair("eip-1.md", "preamble-author"),
+            pair("eip-1.md", "preamble-author"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn sorted() {
+    let unsorted = run(Json::default()).await;
+    assert_eq!(
+        summarize(unsorted),
+        [
+            pair("eip-2.md", "preamble-trim"),
+            pair("eip-1.md", "preamble-author"),
+            pair("eip-1.md", "preamble-author"),
+            pair("eip-1.md", "preamble-trim"),
+        ]
+    );
+
+    let sorted = run(Sorted::new(Json::default()))
+        .await
+        .into_inner()
+        .unwrap();
+    assert_eq!(
+        summarize(sorted),
+        [
+            pair("eip-1.md", "preamble-author"),
+            pair("eip-1.md", "preamble-author"),
+            pair("eip-1.md", "preamble-trim"),
+            pair("eip-2.md", "preamble-trim"),
+        ]
+    );
+}
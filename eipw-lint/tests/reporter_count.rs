@@ -0,0 +1,60 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Author, Trim};
+use eipw_lint::reporters::{Count, Null};
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+use serde_json::json;
+
+#[tokio::test]
+async fn by_lint_and_origin() {
+    let src = r#"---
+author: Foo <foo@example
+header:  value
+---
+hello world"#;
+
+    let reporter = Linter::new(Count::new(Null))
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .check_slice(Some("eip-2.md"), "---\nheader:  value\n---\nhello world")
+        .run()
+        .await
+        .unwrap();
+
+    let zero = json!({ "error": 0, "warning": 0, "info": 0, "note": 0, "help": 0 });
+    let counts = |error: usize, warning: usize| {
+        let mut value = zero.clone();
+        value["error"] = error.into();
+        value["warning"] = warning.into();
+        value
+    };
+
+    assert_eq!(
+        serde_json::to_value(reporter.counts_by_lint()).unwrap(),
+        json!({
+            "preamble-author": counts(2, 0),
+            "preamble-trim": counts(0, 2),
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(reporter.counts_by_origin()).unwrap(),
+        json!({
+            "eip-1.md": counts(2, 1),
+            "eip-2.md": counts(0, 1),
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(reporter.counts()).unwrap(),
+        counts(2, 2)
+    );
+}
@@ -0,0 +1,104 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Author, Trim};
+use eipw_lint::reporters::Gitlab;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+use serde_json::{json, Value};
+
+async fn check(origin: &str, src: &str) -> Vec<Value> {
+    let reporter = Linter::new(Gitlab::default())
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some(origin), src)
+        .run()
+        .await
+        .unwrap();
+
+    match serde_json::to_value(&reporter).unwrap() {
+        Value::Array(a) => a,
+        _ => unreachable!(),
+    }
+}
+
+#[tokio::test]
+async fn issues() {
+    let src = r#"---
+author: Foo (@foo)
+header:  value
+---
+hello world"#;
+
+    let mut issues = check("eip-1.md", src).await;
+    assert_eq!(issues.len(), 1);
+
+    let issue = issues[0].as_object_mut().unwrap();
+    let fingerprint = issue.remove("fingerprint").unwrap();
+    assert_eq!(fingerprint.as_str().unwrap().len(), 64);
+
+    assert_eq!(
+        Value::Object(issue.clone()),
+        json!({
+            "description": "preamble header `header` has extra whitespace",
+            "check_name": "preamble-trim",
+            "severity": "minor",
+            "location": {
+                "path": "eip-1.md",
+                "lines": { "begin": 3 },
+            },
+        })
+    );
+}
+
+#[tokio::test]
+async fn fingerprint_stable_across_lines() {
+    let before = r#"---
+header:  value
+author: Foo (@foo)
+---
+hello world"#;
+
+    let after = r#"---
+author: Foo (@foo)
+header:   value
+---
+hello world"#;
+
+    let before = check("eip-1.md", before).await;
+    let after = check("eip-1.md", after).await;
+    let other = check(
+        "eip-2.md",
+        r#"---
+header:  value
+author: Foo (@foo)
+---
+hello world"#,
+    )
+    .await;
+
+    assert_eq!(before[0]["location"]["lines"]["begin"], 2);
+    assert_eq!(after[0]["location"]["lines"]["begin"], 3);
+    assert_eq!(before[0]["fingerprint"], after[0]["fingerprint"]);
+    assert_ne!(before[0]["fingerprint"], other[0]["fingerprint"]);
+}
+
+#[tokio::test]
+async fn fingerprint_distinct_for_repeats() {
+    let src = r#"---
+header:  value
+author: Foo (@foo)
+header:  value
+---
+hello world"#;
+
+    let issues = check("eip-1.md", src).await;
+
+    assert_eq!(issues.len(), 2);
+    assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+}
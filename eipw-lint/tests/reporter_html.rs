@@ -0,0 +1,94 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Author, Trim};
+use eipw_lint::reporters::Html;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+async fn report(reporter: Html, src: &str) -> String {
+    Linter::new(reporter)
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-<1>.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .into_html()
+}
+
+fn body(html: &str) -> &str {
+    let start = html.find("\t\t<article>").unwrap();
+    let end = html.find("\t\t</article>").unwrap();
+    &html[start..end]
+}
+
+#[tokio::test]
+async fn messages() {
+    let src = r#"---
+author: Foo <foo@example
+header:  value
+---
+hello world"#;
+
+    let html = report(
+        Html::default().with_docs_url("https://example.com/docs/"),
+        src,
+    )
+    .await;
+
+    assert_eq!(
+        body(&html),
+        r##"		<article>
+			<h1>eipw report</h1>
+			<p>Found 2 errors and 1 warning in 1 file.</p>
+			<nav>
+				<ul>
+					<li><a href="#file-0"><code>eip-&lt;1&gt;.md</code></a>: 2 errors, 1 warning</li>
+				</ul>
+			</nav>
+			<section id="file-0">
+				<h2><code>eip-&lt;1&gt;.md</code></h2>
+				<div class="message error">
+					<h3><span class="level">error</span>[<a href="https://example.com/docs/preamble-author/"><code>preamble-author</code></a>]: author email address is missing a closing `&gt;`</h3>
+					<pre><span class="line-number">2 | </span>author: Foo <mark class="error" title="missing closing `&gt;`">&lt;foo@example</mark></pre>
+					<ul>
+						<li><strong>help</strong>: Try `Random J. User (@username) &lt;test@example.com&gt;` for an author with a GitHub username plus email.</li>
+						<li><strong>help</strong>: Try `Random J. User (@username)` for an author with a GitHub username.</li>
+						<li><strong>help</strong>: Try `Random J. User &lt;test@example.com&gt;` for an author with an email.</li>
+						<li><strong>help</strong>: Try `Random J. User` for an author without contact information.</li>
+					</ul>
+				</div>
+				<div class="message error">
+					<h3><span class="level">error</span>[<a href="https://example.com/docs/preamble-author/"><code>preamble-author</code></a>]: preamble header `author` must contain at least one GitHub username</h3>
+					<pre><span class="line-number">2 | </span>author: Foo &lt;foo@example</pre>
+				</div>
+				<div class="message warning">
+					<h3><span class="level">warning</span>[<a href="https://example.com/docs/preamble-trim/"><code>preamble-trim</code></a>]: preamble header `header` has extra whitespace</h3>
+					<pre><span class="line-number">3 | </span>header:<mark class="warning" title="value has extra whitespace">  value</mark></pre>
+				</div>
+			</section>
+"##
+    );
+}
+
+#[tokio::test]
+async fn stylesheet_and_empty() {
+    let html = report(
+        Html::default().with_stylesheet("body { color: red; } </style>"),
+        "---\nheader: value\n---\nhello world",
+    )
+    .await;
+
+    assert!(html.starts_with("<!DOCTYPE html>\n"));
+    assert!(html.contains("body { color: red; } <\\/style>"));
+    assert_eq!(html.matches("</style>").count(), 1);
+    assert_eq!(
+        body(&html),
+        "\t\t<article>\n\t\t\t<h1>eipw report</h1>\n\t\t\t<p>No problems found.</p>\n"
+    );
+}
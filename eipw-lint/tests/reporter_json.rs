@@ -0,0 +1,41 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::Trim;
+use eipw_lint::reporters::Json;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+use serde_json::json;
+
+#[tokio::test]
+async fn spans() {
+    let src = "---\nheader:  \u{1f6a8} välue\n---\nhello world";
+
+    let reports = Linter::new(Json::default())
+        .clear_lints()
+        .deny("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .into_reports();
+
+    let snippet = &reports[0]["snippets"][0];
+
+    assert_eq!(
+        snippet["annotations"][0]["span"],
+        json!({
+            "start": { "line": 2, "column_utf8": 8, "column_utf16": 8, "column_chars": 8 },
+            "end": { "line": 2, "column_utf8": 21, "column_utf16": 18, "column_chars": 17 },
+        })
+    );
+
+    assert_eq!(
+        snippet["suggestions"][0]["span"],
+        snippet["annotations"][0]["span"]
+    );
+}
@@ -0,0 +1,96 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Author, Trim};
+use eipw_lint::reporters::Junit;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn failures_and_output() {
+    let src = r#"---
+author: Foo <foo@example
+header:  value
+---
+hello world"#;
+
+    let junit = Junit::default();
+    junit.add_source("eip-2.md");
+
+    let reports = Linter::new(junit)
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .into_xml();
+
+    assert_eq!(
+        reports,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="eipw" tests="2" failures="1">
+  <testsuite name="eipw" tests="2" failures="1" errors="0" skipped="0">
+    <testcase name="eip-2.md" classname="eipw"/>
+    <testcase name="eip-1.md" classname="eipw">
+      <failure message="author email address is missing a closing `&gt;`" type="preamble-author">error[preamble-author]: author email address is missing a closing `&gt;`
+ --&gt; eip-1.md:2:13
+  |
+2 | author: Foo &lt;foo@example
+  |             ^^^^^^^^^^^^ missing closing `&gt;`
+  |
+  = help: Try `Random J. User (@username) &lt;test@example.com&gt;` for an author with a GitHub username plus email.
+  = help: Try `Random J. User (@username)` for an author with a GitHub username.
+  = help: Try `Random J. User &lt;test@example.com&gt;` for an author with an email.
+  = help: Try `Random J. User` for an author without contact information.</failure>
+      <failure message="preamble header `author` must contain at least one GitHub username" type="preamble-author">error[preamble-author]: preamble header `author` must contain at least one GitHub username
+ --&gt; eip-1.md
+  |
+2 | author: Foo &lt;foo@example
+  |</failure>
+      <system-out>warning[preamble-trim]: preamble header `header` has extra whitespace
+ --&gt; eip-1.md:3:8
+  |
+3 | header:  value
+  |        ------- value has extra whitespace
+  |
+</system-out>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+    );
+}
+
+#[tokio::test]
+async fn failures_count_cases() {
+    let src = r#"---
+author: Foo <foo@example
+header:  value
+---
+hello world"#;
+
+    let junit = Junit::default();
+    junit.add_source("eip-3.md");
+
+    let reports = Linter::new(junit)
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .deny("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .check_slice(Some("eip-2.md"), src)
+        .run()
+        .await
+        .unwrap()
+        .into_xml();
+
+    let failures = reports.matches("<failure ").count();
+    assert_eq!(failures, 6);
+
+    assert!(reports.contains(r#"<testsuites name="eipw" tests="3" failures="2">"#));
+    assert!(reports.contains(r#"<testsuite name="eipw" tests="3" failures="2" errors="0""#));
+}
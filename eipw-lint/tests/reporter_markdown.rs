@@ -0,0 +1,107 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Author, Trim};
+use eipw_lint::reporters::Markdown;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn summary_and_details() {
+    let src = r#"---
+author: Foo <foo@example
+header:  value
+---
+hello world"#;
+
+    let reports = Linter::new(Markdown::default())
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), src)
+        .check_slice(Some("eip-|2|.md"), "---\nheader:  value\n---\nhello world")
+        .run()
+        .await
+        .unwrap()
+        .into_markdown();
+
+    assert_eq!(
+        reports,
+        r#"## eipw
+
+Found **2 errors** and **2 warnings** in **2 files**.
+
+| Lint | Errors | Warnings | Other |
+| --- | ---: | ---: | ---: |
+| `preamble-author` | 2 | 0 | 0 |
+| `preamble-trim` | 0 | 2 | 0 |
+
+| File | Errors | Warnings | Other |
+| --- | ---: | ---: | ---: |
+| `eip-1.md` | 2 | 1 | 0 |
+| `eip-\|2\|.md` | 0 | 1 | 0 |
+
+<details>
+<summary><code>eip-1.md</code>: 2 errors, 1 warning</summary>
+
+```text
+error[preamble-author]: author email address is missing a closing `>`
+ --> eip-1.md:2:13
+  |
+2 | author: Foo <foo@example
+  |             ^^^^^^^^^^^^ missing closing `>`
+  |
+  = help: Try `Random J. User (@username) <test@example.com>` for an author with a GitHub username plus email.
+  = help: Try `Random J. User (@username)` for an author with a GitHub username.
+  = help: Try `Random J. User <test@example.com>` for an author with an email.
+  = help: Try `Random J. User` for an author without contact information.
+
+error[preamble-author]: preamble header `author` must contain at least one GitHub username
+ --> eip-1.md
+  |
+2 | author: Foo <foo@example
+  |
+
+warning[preamble-trim]: preamble header `header` has extra whitespace
+ --> eip-1.md:3:8
+  |
+3 | header:  value
+  |        ------- value has extra whitespace
+  |
+```
+
+</details>
+
+<details>
+<summary><code>eip-|2|.md</code>: 0 errors, 1 warning</summary>
+
+```text
+warning[preamble-trim]: preamble header `header` has extra whitespace
+ --> eip-|2|.md:2:8
+  |
+2 | header:  value
+  |        ------- value has extra whitespace
+  |
+```
+
+</details>
+"#
+    );
+}
+
+#[tokio::test]
+async fn empty() {
+    let reports = Linter::new(Markdown::default())
+        .clear_lints()
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), "---\nheader: value\n---\nhello world")
+        .run()
+        .await
+        .unwrap()
+        .into_markdown();
+
+    assert_eq!(reports, "## eipw\n\nNo problems found.\n");
+}
@@ -0,0 +1,47 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::{Author, Trim};
+use eipw_lint::reporters::{Json, Ndjson, Reporter};
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+use serde_json::Value;
+
+const SRC: &str = r#"---
+author: Foo <foo@example
+header:  value
+---
+hello world"#;
+
+async fn run<R: Reporter>(reporter: R) -> R {
+    Linter::new(reporter)
+        .clear_lints()
+        .deny("preamble-author", Author("author"))
+        .warn("preamble-trim", Trim)
+        .check_slice(Some("eip-1.md"), SRC)
+        .run()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn one_message_per_line() {
+    let output = run(Ndjson::new(Vec::new())).await.into_inner();
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(output.ends_with('\n'));
+
+    let lines: Vec<Value> = output
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+
+    let expected = run(Json::default()).await.into_reports();
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines, expected);
+}
@@ -0,0 +1,161 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::markdown::RelativeLinks;
+use eipw_lint::lints::preamble::Trim;
+use eipw_lint::reporters::{Rdjson, Rdjsonl, Reporter};
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+use serde_json::{json, Value};
+
+const SRC: &str = r#"---
+header:  välue
+---
+See [x](https://eips.ethereum.org/EIPS/eip-20)."#;
+
+async fn run<R: Reporter>(reporter: R) -> R {
+    Linter::new(reporter)
+        .clear_lints()
+        .warn("preamble-trim", Trim)
+        .deny(
+            "markdown-rel-links",
+            RelativeLinks {
+                exceptions: Vec::<&str>::new(),
+            },
+        )
+        .check_slice(Some("eip-1.md"), SRC)
+        .run()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn diagnostics_with_suggestions() {
+    let reporter = run(Rdjson::default().with_docs_url("https://example.com/")).await;
+    let mut value = serde_json::to_value(&reporter).unwrap();
+
+    for diagnostic in value["diagnostics"].as_array_mut().unwrap() {
+        let output = diagnostic
+            .as_object_mut()
+            .unwrap()
+            .remove("original_output")
+            .unwrap();
+        assert!(output.as_str().unwrap().contains("eip-1.md"));
+    }
+
+    let source = json!({ "name": "eipw", "url": "https://github.com/ethereum/eipw" });
+
+    assert_eq!(
+        value,
+        json!({
+            "source": source,
+            "diagnostics": [
+                {
+                    "message": "non-relative link or image",
+                    "location": {
+                        "path": "eip-1.md",
+                        "range": {
+                            "start": { "line": 4, "column": 5 },
+                            "end": { "line": 4, "column": 47 },
+                        },
+                    },
+                    "severity": "ERROR",
+                    "source": source,
+                    "code": {
+                        "value": "markdown-rel-links",
+                        "url": "https://example.com/markdown-rel-links/",
+                    },
+                    "suggestions": [
+                        {
+                            "range": {
+                                "start": { "line": 4, "column": 9 },
+                                "end": { "line": 4, "column": 46 },
+                            },
+                            "text": "./eip-20.md",
+                        },
+                    ],
+                },
+                {
+                    "message": "preamble header `header` has extra whitespace",
+                    "location": {
+                        "path": "eip-1.md",
+                        "range": {
+                            "start": { "line": 2, "column": 8 },
+                            "end": { "line": 2, "column": 16 },
+                        },
+                    },
+                    "severity": "WARNING",
+                    "source": source,
+                    "code": {
+                        "value": "preamble-trim",
+                        "url": "https://example.com/preamble-trim/",
+                    },
+                    "suggestions": [
+                        {
+                            "range": {
+                                "start": { "line": 2, "column": 8 },
+                                "end": { "line": 2, "column": 16 },
+                            },
+                            "text": " välue",
+                        },
+                    ],
+                },
+            ],
+        })
+    );
+}
+
+#[tokio::test]
+async fn one_diagnostic_per_line() {
+    let output = run(Rdjsonl::new(Vec::new())).await.into_inner();
+    let output = String::from_utf8(output).unwrap();
+
+    let lines: Vec<Value> = output
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["code"], json!({ "value": "markdown-rel-links" }));
+    assert_eq!(lines[1]["severity"], "WARNING");
+}
+
+#[tokio::test]
+async fn suggestion_replaces_destination() {
+    let src = r#"---
+header: value
+---
+See [https://eips.ethereum.org/EIPS/eip-20](https://eips.ethereum.org/EIPS/eip-20)."#;
+
+    let reporter = Linter::new(Rdjson::default())
+        .clear_lints()
+        .deny(
+            "markdown-rel-links",
+            RelativeLinks {
+                exceptions: Vec::<&str>::new(),
+            },
+        )
+        .check_slice(Some("eip-1.md"), src)
+        .run()
+        .await
+        .unwrap();
+
+    let value = serde_json::to_value(&reporter).unwrap();
+
+    assert_eq!(
+        value["diagnostics"][0]["suggestions"],
+        json!([
+            {
+                "range": {
+                    "start": { "line": 4, "column": 45 },
+                    "end": { "line": 4, "column": 82 },
+                },
+                "text": "./eip-20.md",
+            },
+        ])
+    );
+}
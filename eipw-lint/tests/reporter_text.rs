@@ -0,0 +1,57 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::Trim;
+use eipw_lint::reporters::Text;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+const SRC: &str = r#"---
+header:  value
+---
+hello world"#;
+
+async fn render(color: bool) -> String {
+    Linter::new(Text::new(String::new()).with_color(color))
+        .clear_lints()
+        .deny("preamble-trim", Trim)
+        .check_slice(None, SRC)
+        .run()
+        .await
+        .unwrap()
+        .into_inner()
+}
+
+#[tokio::test]
+async fn plain() {
+    let reports = render(false).await;
+    assert!(!reports.contains('\x1b'));
+    assert_eq!(
+        reports,
+        r#"error[preamble-trim]: preamble header `header` has extra whitespace
+  |
+2 | header:  value
+  |        ^^^^^^^ value has extra whitespace
+  |
+"#
+    );
+}
+
+#[tokio::test]
+async fn styled() {
+    let reports = render(true).await;
+    assert!(reports.contains("\x1b["));
+
+    let stripped = reports.split('\x1b').fold(String::new(), |mut acc, part| {
+        match part.strip_prefix('[') {
+            Some(rest) => acc.push_str(&rest[rest.find('m').unwrap() + 1..]),
+            None => acc.push_str(part),
+        }
+        acc
+    });
+
+    assert_eq!(stripped, render(false).await);
+}
@@ -0,0 +1,72 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_lint::lints::preamble::Trim;
+use eipw_lint::lints::{Context, Error, Lint};
+use eipw_lint::reporters::Null;
+use eipw_lint::timings::Timings;
+use eipw_lint::Linter;
+use pretty_assertions::assert_eq;
+
+use std::time::Duration;
+
+const SRC: &str = r#"---
+header: value0
+---
+hello world"#;
+
+#[derive(Debug)]
+struct Slow;
+
+impl Lint for Slow {
+    fn lint<'a>(&self, _slug: &'a str, _ctx: &Context<'a, '_>) -> Result<(), Error> {
+        std::thread::sleep(Duration::from_millis(10));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn timings() {
+    let timings = Timings::default();
+
+    Linter::new(Null)
+        .clear_lints()
+        .deny("preamble-trim", Trim)
+        .deny("slow", Slow)
+        .record_timings(&timings)
+        .check_slice(Some("first.md"), SRC)
+        .check_slice(Some("second.md"), SRC)
+        .run()
+        .await
+        .unwrap();
+
+    let lints = timings.lints();
+    assert_eq!(
+        lints.keys().map(String::as_str).collect::<Vec<_>>(),
+        ["preamble-trim", "slow"],
+    );
+
+    let slow = lints["slow"];
+    assert!(slow.lint >= Duration::from_millis(20));
+    assert_eq!(slow.total(), slow.prepare + slow.find_resources + slow.lint);
+    assert!(timings.parse() > Duration::ZERO);
+}
+
+#[tokio::test]
+async fn not_recorded() {
+    let timings = Timings::default();
+
+    Linter::new(Null)
+        .clear_lints()
+        .deny("slow", Slow)
+        .check_slice(None, SRC)
+        .run()
+        .await
+        .unwrap();
+
+    assert!(timings.lints().is_empty());
+    assert_eq!(timings.parse(), Duration::ZERO);
+}
@@ -0,0 +1,547 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+//! Parser for author lists, like
+//! `Random J. User (@username) <test@example.com>, Another User`.
+//!
+//! Names may be quoted (`"User, Random J."`), and email addresses follow the
+//! `addr-spec` grammar from [RFC 5322], so quoted local parts
+//! (`"random,user"@example.com`) and domain literals are accepted.
+//!
+//! [RFC 5322]: https://www.rfc-editor.org/rfc/rfc5322#section-3.4.1
+
+use snafu::Snafu;
+
+use std::ops::Range;
+
+/// Errors that can arise while parsing a single author. See [`parse`].
+///
+/// Every variant carries a byte range into the text given to [`parse`].
+#[derive(Debug, Clone, PartialEq, Eq, Snafu)]
+#[snafu(module)]
+#[non_exhaustive]
+pub enum AuthorError {
+    /// The author has no name before the contact information.
+    #[snafu(context(suffix(false)), display("author is missing a name"))]
+    MissingName {
+        /// Location of the nameless author.
+        span: Range<usize>,
+    },
+
+    /// A quoted name was never closed.
+    #[snafu(
+        context(suffix(false)),
+        display("author name has an unterminated quote")
+    )]
+    UnterminatedQuote {
+        /// Location of the opening quote through the end of the author.
+        span: Range<usize>,
+    },
+
+    /// A character that may only appear in contact information (or not at
+    /// all) appeared in the name.
+    #[snafu(
+        context(suffix(false)),
+        display("author name cannot contain `{character}`")
+    )]
+    UnexpectedCharacter {
+        /// Location of the character.
+        span: Range<usize>,
+        /// The offending character.
+        character: char,
+    },
+
+    /// A `(` or `<` was not preceded by whitespace.
+    #[snafu(
+        context(suffix(false)),
+        display("author contact information must be preceded by a space")
+    )]
+    MissingSpace {
+        /// Location of the `(` or `<`.
+        span: Range<usize>,
+    },
+
+    /// A `(` was never closed.
+    #[snafu(
+        context(suffix(false)),
+        display("author GitHub username is missing a closing `)`")
+    )]
+    UnterminatedHandle {
+        /// Location of the `(` through the end of the author.
+        span: Range<usize>,
+    },
+
+    /// The text between `(` and `)` was not `@` followed by a username.
+    #[snafu(context(suffix(false)), display("author GitHub username is invalid"))]
+    InvalidHandle {
+        /// Location of the parenthesized handle.
+        span: Range<usize>,
+    },
+
+    /// A `<` was never closed.
+    #[snafu(
+        context(suffix(false)),
+        display("author email address is missing a closing `>`")
+    )]
+    UnterminatedEmail {
+        /// Location of the `<` through the end of the author.
+        span: Range<usize>,
+    },
+
+    /// The text between `<` and `>` was not an email address.
+    #[snafu(context(suffix(false)), display("author email address is invalid"))]
+    InvalidEmail {
+        /// Location of the bracketed email address.
+        span: Range<usize>,
+    },
+
+    /// Text followed the contact information.
+    #[snafu(
+        context(suffix(false)),
+        display("author has unexpected text after the contact information")
+    )]
+    TrailingText {
+        /// Location of the extra text.
+        span: Range<usize>,
+    },
+}
+
+impl AuthorError {
+    /// Byte range of the problem, relative to the text given to [`parse`].
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            Self::MissingName { span }
+            | Self::UnterminatedQuote { span }
+            | Self::UnexpectedCharacter { span, .. }
+            | Self::MissingSpace { span }
+            | Self::UnterminatedHandle { span }
+            | Self::InvalidHandle { span }
+            | Self::UnterminatedEmail { span }
+            | Self::InvalidEmail { span }
+            | Self::TrailingText { span } => span.clone(),
+        }
+    }
+}
+
+/// One successfully parsed entry from an author list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Author<'a> {
+    span: Range<usize>,
+    name: &'a str,
+    handle: Option<&'a str>,
+    email: Option<&'a str>,
+}
+
+impl<'a> Author<'a> {
+    /// Byte range of the author (without surrounding whitespace), relative
+    /// to the text given to [`parse`].
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Name of the author, including any quotes.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// GitHub username, without the `(@` and `)`.
+    pub fn handle(&self) -> Option<&'a str> {
+        self.handle
+    }
+
+    /// Email address, without the `<` and `>`.
+    pub fn email(&self) -> Option<&'a str> {
+        self.email
+    }
+}
+
+/// Parse a comma separated list of authors, returning one result per entry.
+pub fn parse(text: &str) -> Vec<Result<Author<'_>, AuthorError>> {
+    split(text)
+        .into_iter()
+        .map(|(offset, item)| parse_one(offset, item))
+        .collect()
+}
+
+/// Find the end of the quoted string starting at `start`, returning the index
+/// just past the closing quote.
+fn quoted_end(text: &str, start: usize) -> Option<usize> {
+    let mut escaped = false;
+    for (idx, c) in text[start + 1..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(start + 1 + idx + 1),
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Split on commas that aren't inside a quoted string.
+fn split(text: &str) -> Vec<(usize, &str)> {
+    let mut items = Vec::new();
+    let mut start = 0;
+    let mut idx = 0;
+
+    while idx < text.len() {
+        match text.as_bytes()[idx] {
+            b'"' => match quoted_end(text, idx) {
+                Some(end) => idx = end,
+                None => break,
+            },
+            b',' => {
+                items.push((start, &text[start..idx]));
+                idx += 1;
+                start = idx;
+            }
+            _ => idx += 1,
+        }
+    }
+
+    items.push((start, &text[start..]));
+    items
+}
+
+fn parse_one(offset: usize, item: &str) -> Result<Author<'_>, AuthorError> {
+    use self::author_error::*;
+
+    let start = item.len() - item.trim_start().len();
+    let end = item.trim_end().len();
+    let span = |r: Range<usize>| offset + r.start..offset + r.end;
+
+    if start == item.len() {
+        return MissingName {
+            span: span(0..item.len()),
+        }
+        .fail();
+    }
+
+    // Name: quoted strings and anything that can't begin contact information.
+    let mut pos = start;
+    while pos < end {
+        let c = item[pos..].chars().next().unwrap();
+        match c {
+            '"' => match quoted_end(item, pos) {
+                Some(e) => pos = e,
+                None => {
+                    return UnterminatedQuote {
+                        span: span(pos..end),
+                    }
+                    .fail()
+                }
+            },
+            '(' | '<' => break,
+            ')' | '>' | '@' => {
+                return UnexpectedCharacter {
+                    span: span(pos..pos + 1),
+                    character: c,
+                }
+                .fail()
+            }
+            _ => pos += c.len_utf8(),
+        }
+    }
+
+    let name = item[start..pos].trim_end();
+    if name.is_empty() {
+        return MissingName {
+            span: span(start..end),
+        }
+        .fail();
+    }
+
+    let mut handle = None;
+    let mut email = None;
+
+    if item[pos..end].starts_with('(') {
+        if !item[..pos].ends_with(char::is_whitespace) {
+            return MissingSpace {
+                span: span(pos..pos + 1),
+            }
+            .fail();
+        }
+
+        let close = match item[pos..end].find(')') {
+            Some(c) => pos + c,
+            None => {
+                return UnterminatedHandle {
+                    span: span(pos..end),
+                }
+                .fail()
+            }
+        };
+
+        let inner = &item[pos + 1..close];
+        match inner.strip_prefix('@') {
+            Some(h) if is_handle(h) => handle = Some(h),
+            _ => {
+                return InvalidHandle {
+                    span: span(pos..close + 1),
+                }
+                .fail()
+            }
+        }
+
+        pos = close + 1;
+    }
+
+    pos += item[pos..end].len() - item[pos..end].trim_start().len();
+
+    if item[pos..end].starts_with('<') {
+        if !item[..pos].ends_with(char::is_whitespace) {
+            return MissingSpace {
+                span: span(pos..pos + 1),
+            }
+            .fail();
+        }
+
+        let close = match angle_end(item, pos, end) {
+            Some(c) => c,
+            None => {
+                return UnterminatedEmail {
+                    span: span(pos..end),
+                }
+                .fail()
+            }
+        };
+
+        let inner = &item[pos + 1..close];
+        if !is_addr_spec(inner) {
+            return InvalidEmail {
+                span: span(pos..close + 1),
+            }
+            .fail();
+        }
+
+        email = Some(inner);
+        pos = close + 1;
+        pos += item[pos..end].len() - item[pos..end].trim_start().len();
+    }
+
+    if pos != end {
+        return TrailingText {
+            span: span(pos..end),
+        }
+        .fail();
+    }
+
+    Ok(Author {
+        span: span(start..end),
+        name,
+        handle,
+        email,
+    })
+}
+
+/// Find the `>` closing the `<` at `start`, skipping over quoted strings.
+fn angle_end(text: &str, start: usize, end: usize) -> Option<usize> {
+    let mut idx = start + 1;
+    while idx < end {
+        match text.as_bytes()[idx] {
+            b'"' => idx = quoted_end(&text[..end], idx)?,
+            b'>' => return Some(idx),
+            _ => idx += 1,
+        }
+    }
+    None
+}
+
+fn is_handle(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || !c.is_ascii() || "!#$%&'*+-/=?^_`{|}~".contains(c)
+}
+
+fn is_dot_atom(text: &str) -> bool {
+    text.split('.')
+        .all(|atom| !atom.is_empty() && atom.chars().all(is_atext))
+}
+
+fn is_addr_spec(text: &str) -> bool {
+    let (local, domain) = if text.starts_with('"') {
+        let end = match quoted_end(text, 0) {
+            Some(e) => e,
+            None => return false,
+        };
+        match text[end..].strip_prefix('@') {
+            Some(domain) => (&text[..end], domain),
+            None => return false,
+        }
+    } else {
+        match text.split_once('@') {
+            Some(parts) => parts,
+            None => return false,
+        }
+    };
+
+    let local_ok = local.starts_with('"') || is_dot_atom(local);
+
+    let domain_ok = match domain.strip_prefix('[') {
+        Some(literal) => match literal.strip_suffix(']') {
+            Some(l) => !l.is_empty() && !l.contains(['[', ']', '\\']),
+            None => false,
+        },
+        // Dotless domains are technically valid, but never what an author
+        // meant to write.
+        None => domain.contains('.') && is_dot_atom(domain),
+    };
+
+    local_ok && domain_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    fn one(text: &str) -> Result<Author<'_>, AuthorError> {
+        let mut authors = parse(text);
+        assert_eq!(authors.len(), 1);
+        authors.pop().unwrap()
+    }
+
+    #[test]
+    fn name_only() {
+        let author = one(" Random J. User ").unwrap();
+        assert_eq!(author.name(), "Random J. User");
+        assert_eq!(author.handle(), None);
+        assert_eq!(author.email(), None);
+        assert_eq!(author.span(), 1..15);
+    }
+
+    #[test]
+    fn handle_and_email() {
+        let author = one("Random J. User (@user-1) <test@example.com>").unwrap();
+        assert_eq!(author.name(), "Random J. User");
+        assert_eq!(author.handle(), Some("user-1"));
+        assert_eq!(author.email(), Some("test@example.com"));
+    }
+
+    #[test]
+    fn list() {
+        let authors = parse("Foo <foo@example.com>, Bar (@bar), Baz");
+        let names: Vec<_> = authors.into_iter().map(|a| a.unwrap().name()).collect();
+        assert_eq!(names, ["Foo", "Bar", "Baz"]);
+    }
+
+    #[test]
+    fn quoted_name_with_comma() {
+        let authors = parse(r#""User, Random \"RJ\"" (@user), Bar"#);
+        assert_eq!(authors.len(), 2);
+        assert_eq!(
+            authors[0].as_ref().unwrap().name(),
+            r#""User, Random \"RJ\"""#
+        );
+        assert_eq!(authors[1].as_ref().unwrap().span(), 31..34);
+    }
+
+    #[test]
+    fn quoted_local_part() {
+        let author = one(r#"Foo <"foo,bar>"@example.com>"#).unwrap();
+        assert_eq!(author.email(), Some(r#""foo,bar>"@example.com"#));
+    }
+
+    #[test]
+    fn domain_literal() {
+        let author = one("Foo <foo@[192.0.2.1]>").unwrap();
+        assert_eq!(author.email(), Some("foo@[192.0.2.1]"));
+    }
+
+    #[test]
+    fn missing_name() {
+        assert_matches!(one(" (@foo)"), Err(AuthorError::MissingName { span }) if span == (1..7));
+        assert_matches!(one(""), Err(AuthorError::MissingName { span }) if span == (0..0));
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        assert_matches!(
+            one(r#"Foo "Bar (@bar)"#),
+            Err(AuthorError::UnterminatedQuote { span }) if span == (4..15)
+        );
+    }
+
+    #[test]
+    fn unexpected_character() {
+        assert_matches!(
+            one("John Doe @johndoe)"),
+            Err(AuthorError::UnexpectedCharacter { span, character: '@' }) if span == (9..10)
+        );
+    }
+
+    #[test]
+    fn missing_space() {
+        assert_matches!(
+            one("Foo(@foo)"),
+            Err(AuthorError::MissingSpace { span }) if span == (3..4)
+        );
+        assert_matches!(
+            one("Foo (@foo)<foo@example.com>"),
+            Err(AuthorError::MissingSpace { span }) if span == (10..11)
+        );
+    }
+
+    #[test]
+    fn unterminated_handle() {
+        assert_matches!(
+            one("Bánana ("),
+            Err(AuthorError::UnterminatedHandle { span }) if span == (8..9)
+        );
+    }
+
+    #[test]
+    fn invalid_handle() {
+        assert_matches!(
+            one("Jenny Doe (jenny@example.com)"),
+            Err(AuthorError::InvalidHandle { span }) if span == (10..29)
+        );
+        assert_matches!(one("Foo (@)"), Err(AuthorError::InvalidHandle { .. }));
+    }
+
+    #[test]
+    fn unterminated_email() {
+        assert_matches!(
+            one("John Doe <john.doe@example.com"),
+            Err(AuthorError::UnterminatedEmail { span }) if span == (9..30)
+        );
+    }
+
+    #[test]
+    fn invalid_email() {
+        for email in [
+            "<@example>",
+            "<foo@example>",
+            "<foo@@example.com>",
+            "<foo..bar@example.com>",
+            "<foo@example.com.>",
+            "<foo bar@example.com>",
+            "<foo@[192.0.2.1>",
+        ] {
+            let text = format!("Foo {}", email);
+            assert_matches!(
+                one(&text),
+                Err(AuthorError::InvalidEmail { .. }),
+                "{}",
+                email
+            );
+        }
+    }
+
+    #[test]
+    fn trailing_text() {
+        assert_matches!(
+            one("John Doe <john.doe@example.com>a"),
+            Err(AuthorError::TrailingText { span }) if span == (31..32)
+        );
+        assert_matches!(
+            one("Foo <foo@example.com> (@foo)"),
+            Err(AuthorError::TrailingText { span }) if span == (22..28)
+        );
+    }
+}
@@ -0,0 +1,65 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use eipw_preamble::{author, Preamble};
+use eipw_snippets::Message;
+
+use proptest::prelude::*;
+
+fn check_message(message: &Message<'_>) {
+    for snippet in &message.snippets {
+        for annotation in &snippet.annotations {
+            let range = &annotation.range;
+            assert!(range.start <= range.end, "{:?}", range);
+            assert!(snippet.source.is_char_boundary(range.start), "{:?}", range);
+            assert!(snippet.source.is_char_boundary(range.end), "{:?}", range);
+        }
+    }
+
+    message.footer.iter().for_each(check_message);
+}
+
+fn preamble_line() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-z-]{0,8}:[^\n]{0,16}",
+        "[^\n]{0,16}",
+        Just("author: \"Doe, J\u{e1}ne\" (@jane) <\"j,d\"@example.com>".to_string()),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn split_never_panics(text in any::<String>()) {
+        let _ = Preamble::split(&text);
+    }
+
+    #[test]
+    fn parse_never_panics(lines in prop::collection::vec(preamble_line(), 0..8)) {
+        let text = lines.join("\n");
+        match Preamble::parse(None, &text) {
+            Ok(preamble) => {
+                for field in preamble.fields() {
+                    assert_eq!(field.source(), format!("{}:{}", field.name(), field.value()));
+                }
+            }
+            Err(e) => e.into_errors().iter().for_each(check_message),
+        }
+    }
+
+    #[test]
+    fn author_spans_on_char_boundaries(text in r#"[ a-zA-Z\u{e1}\u{1F600},"()<>@.\[\]\\-]{0,40}"#) {
+        for result in author::parse(&text) {
+            let span = match result {
+                Ok(a) => a.span(),
+                Err(e) => e.span(),
+            };
+
+            assert!(span.start <= span.end, "{:?}", span);
+            assert!(text.is_char_boundary(span.start), "{:?}", span);
+            assert!(text.is_char_boundary(span.end), "{:?}", span);
+        }
+    }
+}
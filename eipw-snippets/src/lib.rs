@@ -8,16 +8,19 @@ use annotate_snippets as ann;
 use serde::{Deserialize, Serialize};
 
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::ops::Range;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Message<'a> {
     pub level: Level,
     #[serde(default)]
     pub id: Option<Cow<'a, str>>,
     pub title: Cow<'a, str>,
+    #[serde(default)]
     pub snippets: Vec<Snippet<'a>>,
+    #[serde(default)]
     pub footer: Vec<Message<'a>>,
 }
 
@@ -46,6 +49,17 @@ impl<'a> Message<'a> {
         self.footer.extend(footer);
         self
     }
+
+    /// Copy any borrowed text, so the message no longer depends on its source.
+    pub fn into_owned(self) -> Message<'static> {
+        Message {
+            level: self.level,
+            id: self.id.map(|i| Cow::Owned(i.into_owned())),
+            title: Cow::Owned(self.title.into_owned()),
+            snippets: self.snippets.into_iter().map(Snippet::into_owned).collect(),
+            footer: self.footer.into_iter().map(Message::into_owned).collect(),
+        }
+    }
 }
 
 impl<'a, 'b> From<&'b Message<'a>> for ann::Message<'b> {
@@ -63,17 +77,27 @@ impl<'a, 'b> From<&'b Message<'a>> for ann::Message<'b> {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Snippet<'a> {
     #[serde(default)]
     pub origin: Option<Cow<'a, str>>,
+    #[serde(default = "default_line_start")]
     pub line_start: usize,
 
     pub source: Cow<'a, str>,
+    #[serde(default)]
     pub annotations: Vec<Annotation<'a>>,
 
+    #[serde(default)]
     pub fold: bool,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<Suggestion<'a>>,
+}
+
+fn default_line_start() -> usize {
+    1
 }
 
 impl<'a> Snippet<'a> {
@@ -84,6 +108,7 @@ impl<'a> Snippet<'a> {
             source: Cow::Borrowed(source),
             annotations: vec![],
             fold: false,
+            suggestions: vec![],
         }
     }
 
@@ -111,13 +136,96 @@ impl<'a> Snippet<'a> {
         self.fold = fold;
         self
     }
+
+    pub fn suggestion(mut self, suggestion: Suggestion<'a>) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+
+    /// Resolve a byte index into `source` to a line and column in the file.
+    ///
+    /// Indices past the end of `source` are clamped, and indices inside a
+    /// character are moved to its start.
+    pub fn position(&self, index: usize) -> Position {
+        let source = &self.source;
+        let mut index = index.min(source.len());
+        while !source.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        let before = &source[..index];
+        let line_begin = before.rfind('\n').map_or(0, |i| i + 1);
+        let text = &before[line_begin..];
+
+        Position {
+            line: self.line_start + before.matches('\n').count(),
+            column_utf8: text.len() + 1,
+            column_utf16: text.encode_utf16().count() + 1,
+            column_chars: text.chars().count() + 1,
+        }
+    }
+
+    /// Resolve a byte range of `source` to positions in the file.
+    pub fn span(&self, range: &Range<usize>) -> Span {
+        Span {
+            start: self.position(range.start),
+            end: self.position(range.end),
+        }
+    }
+
+    /// Resolve each annotation's range, in order.
+    pub fn annotation_spans(&self) -> impl Iterator<Item = Span> + '_ {
+        self.annotations.iter().map(|a| self.span(&a.range))
+    }
+
+    pub fn into_owned(self) -> Snippet<'static> {
+        Snippet {
+            origin: self.origin.map(|o| Cow::Owned(o.into_owned())),
+            line_start: self.line_start,
+            source: Cow::Owned(self.source.into_owned()),
+            annotations: self
+                .annotations
+                .into_iter()
+                .map(Annotation::into_owned)
+                .collect(),
+            fold: self.fold,
+            suggestions: self
+                .suggestions
+                .into_iter()
+                .map(Suggestion::into_owned)
+                .collect(),
+        }
+    }
 }
 
 impl<'a, 'b> From<&'b Snippet<'a>> for ann::Snippet<'b> {
     fn from(value: &'b Snippet<'a>) -> Self {
-        let snip = Self::source(&value.source)
+        let source = &value.source;
+
+        // Deserialized snippets may have spans that don't fit their source,
+        // which `annotate_snippets` can't render.
+        let clamp = |mut idx: usize| {
+            idx = idx.min(source.len());
+            while !source.is_char_boundary(idx) {
+                idx -= 1;
+            }
+            idx
+        };
+
+        let annotations = value.annotations.iter().map(move |a| {
+            let start = clamp(a.range.start);
+            let end = clamp(a.range.end).max(start);
+            let annotation = ann::Level::from(a.level).span(start..end);
+
+            match a.label {
+                Some(ref label) => annotation.label(label),
+                None => annotation,
+            }
+        });
+
+        let snip = Self::source(source)
             .line_start(value.line_start)
-            .annotations(value.annotations.iter().map(Into::into))
+            .annotations(annotations)
             .fold(value.fold);
 
         if let Some(ref origin) = value.origin {
@@ -128,7 +236,7 @@ impl<'a, 'b> From<&'b Snippet<'a>> for ann::Snippet<'b> {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Annotation<'a> {
     pub range: Range<usize>,
@@ -142,6 +250,14 @@ impl<'a> Annotation<'a> {
         self.label = Some(Cow::Borrowed(label));
         self
     }
+
+    pub fn into_owned(self) -> Annotation<'static> {
+        Annotation {
+            range: self.range,
+            label: self.label.map(|l| Cow::Owned(l.into_owned())),
+            level: self.level,
+        }
+    }
 }
 
 impl<'a, 'b> From<&'b Annotation<'a>> for ann::Annotation<'b> {
@@ -156,16 +272,102 @@ impl<'a, 'b> From<&'b Annotation<'a>> for ann::Annotation<'b> {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+/// A line and column in the file a [`Snippet`] was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Position {
+    /// Line number, starting at one.
+    pub line: usize,
+
+    /// Column, starting at one, counted in UTF-8 bytes.
+    pub column_utf8: usize,
+
+    /// Column, starting at one, counted in UTF-16 code units (as used by
+    /// JavaScript strings and the Language Server Protocol.)
+    pub column_utf16: usize,
+
+    /// Column, starting at one, counted in characters (Unicode scalar values.)
+    #[serde(default)]
+    pub column_chars: usize,
+}
+
+/// The resolved start and (exclusive) end of a range in a [`Snippet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// A machine-applicable fix, replacing `range` of the snippet's source with
+/// `replacement`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Suggestion<'a> {
+    pub range: Range<usize>,
+    pub replacement: Cow<'a, str>,
+}
+
+impl<'a> Suggestion<'a> {
+    pub fn new<R>(range: Range<usize>, replacement: R) -> Self
+    where
+        R: Into<Cow<'a, str>>,
+    {
+        Self {
+            range,
+            replacement: replacement.into(),
+        }
+    }
+
+    pub fn into_owned(self) -> Suggestion<'static> {
+        Suggestion {
+            range: self.range,
+            replacement: Cow::Owned(self.replacement.into_owned()),
+        }
+    }
+}
+
+/// How serious a message is.
+///
+/// Levels are ordered by severity, so `Level::Error` is the greatest and
+/// `Level::Help` the least.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 pub enum Level {
+    #[serde(alias = "error")]
     Error,
+    #[serde(alias = "warning")]
     Warning,
+    #[serde(alias = "info")]
     Info,
+    #[serde(alias = "note")]
     Note,
+    #[serde(alias = "help")]
     Help,
 }
 
+impl PartialOrd for Level {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Level {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.severity().cmp(&other.severity())
+    }
+}
+
 impl Level {
+    fn severity(self) -> u8 {
+        match self {
+            Self::Error => 4,
+            Self::Warning => 3,
+            Self::Info => 2,
+            Self::Note => 1,
+            Self::Help => 0,
+        }
+    }
+
     pub fn title(self, title: &str) -> Message<'_> {
         Message {
             level: self,
@@ -242,4 +444,128 @@ mod tests {
             Some(Cow::Borrowed(title))
         );
     }
+
+    #[test]
+    fn json_suggestions() {
+        let snippet = Snippet::source("header:  value");
+        let json = serde_json::to_value(&snippet).unwrap();
+        assert!(json.get("suggestions").is_none());
+
+        let snippet = snippet.suggestion(Suggestion::new(7..14, String::from(" value")));
+        let json = serde_json::to_string(&snippet).unwrap();
+        let actual: Snippet = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(actual.suggestions.len(), 1);
+        assert_eq!(actual.suggestions[0].range, 7..14);
+        assert_eq!(actual.suggestions[0].replacement, " value");
+    }
+
+    #[test]
+    fn deserialize_owned() {
+        fn assert_send<T: Send + 'static>(_: &T) {}
+
+        let json = String::from(
+            r#"{"level":"error","title":"a \"quoted\" title","snippets":[{"source":"x\ty"}]}"#,
+        );
+
+        let message: Message<'static> = serde_json::from_str(&json).unwrap();
+        drop(json);
+        assert_send(&message);
+
+        assert_eq!(message.level, Level::Error);
+        assert_eq!(message.title, "a \"quoted\" title");
+        assert!(message.footer.is_empty());
+        assert_eq!(message.snippets[0].source, "x\ty");
+        assert_eq!(message.snippets[0].line_start, 1);
+        assert!(message.snippets[0].annotations.is_empty());
+    }
+
+    #[test]
+    fn into_owned() {
+        let source = String::from("hello \u{1f6a8} world");
+        let message = Level::Warning
+            .title("title")
+            .snippet(
+                Snippet::source(&source)
+                    .annotation(Level::Warning.span(6..10).label("here"))
+                    .suggestion(Suggestion::new(6..10, "!")),
+            )
+            .into_owned();
+        drop(source);
+
+        assert_eq!(message.snippets[0].source, "hello \u{1f6a8} world");
+        assert_eq!(
+            message.snippets[0].annotations[0].label.as_deref(),
+            Some("here")
+        );
+        assert_eq!(message.snippets[0].suggestions[0].replacement, "!");
+    }
+
+    #[test]
+    fn render_out_of_range() {
+        let json = r#"{
+            "level": "Error",
+            "title": "stale",
+            "snippets": [{
+                "source": "🚨!",
+                "annotations": [
+                    { "range": { "start": 1, "end": 99 }, "level": "Error" },
+                    { "range": { "start": 50, "end": 2 }, "level": "Help" }
+                ]
+            }]
+        }"#;
+
+        let message: Message = serde_json::from_str(json).unwrap();
+        let rendered = ann::Renderer::plain().render((&message).into()).to_string();
+        assert!(rendered.starts_with("error: stale"));
+    }
+
+    #[test]
+    fn positions() {
+        let snippet = Snippet::source("a: \u{1f6a8}x\nb: \u{e9}y\n")
+            .line_start(7)
+            .annotation(Level::Error.span(3..8))
+            .annotation(Level::Error.span(12..15));
+
+        let spans: Vec<_> = snippet.annotation_spans().collect();
+
+        let pos = |line, column_utf8, column_utf16, column_chars| Position {
+            line,
+            column_utf8,
+            column_utf16,
+            column_chars,
+        };
+
+        assert_eq!(spans[0].start, pos(7, 4, 4, 4));
+        assert_eq!(spans[0].end, pos(7, 9, 7, 6));
+        assert_eq!(spans[1].start, pos(8, 4, 4, 4));
+        assert_eq!(spans[1].end, pos(8, 7, 6, 6));
+
+        // Inside the emoji, and past the end.
+        assert_eq!(snippet.position(5), pos(7, 4, 4, 4));
+        assert_eq!(snippet.position(99), pos(9, 1, 1, 1));
+    }
+
+    #[test]
+    fn levels_ordered_by_severity() {
+        let mut levels = vec![
+            Level::Note,
+            Level::Error,
+            Level::Help,
+            Level::Warning,
+            Level::Info,
+        ];
+        levels.sort();
+
+        assert_eq!(
+            levels,
+            [
+                Level::Help,
+                Level::Note,
+                Level::Info,
+                Level::Warning,
+                Level::Error,
+            ]
+        );
+    }
 }
@@ -0,0 +1,19 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![no_main]
+
+use eipw_lint::testing::{run, CheckBoundaries, EveryNode};
+use eipw_lint::Linter;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    run(Linter::new(CheckBoundaries)
+        .clear_lints()
+        .deny("every-node", EveryNode)
+        .check_slice(None, text));
+});
@@ -0,0 +1,38 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![no_main]
+
+use eipw_lint::testing::check_message;
+use eipw_preamble::{author, Preamble};
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let preamble = match Preamble::split(text) {
+        Ok((p, _)) => p,
+        Err(_) => text,
+    };
+
+    let preamble = match Preamble::parse(None, preamble) {
+        Ok(p) => p,
+        Err(e) => {
+            e.into_errors().iter().for_each(check_message);
+            return;
+        }
+    };
+
+    for field in preamble.fields() {
+        for result in author::parse(field.value()) {
+            let span = match result {
+                Ok(a) => a.span(),
+                Err(e) => e.span(),
+            };
+
+            assert!(field.value().get(span).is_some());
+        }
+    }
+});
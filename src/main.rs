@@ -5,17 +5,28 @@
  */
 
 use eipw_lint::config::DefaultOptions;
-use eipw_snippets::Message;
+use eipw_snippets::{Level, Message};
 
 use clap::{Parser, ValueEnum};
 
-use eipw_lint::reporters::{AdditionalHelp, Count, Json, Reporter, Text};
+use eipw_lint::cache::Cache;
+use eipw_lint::reporters::count::Counts;
+use eipw_lint::reporters::{
+    AdditionalHelp, Checkstyle, Count, Error as ReporterError, Gitlab, Html, Json, Junit, Markdown,
+    Ndjson, Rdjson, Rdjsonl, Reporter, Tee, Text,
+};
+use eipw_lint::timings::Timings;
 use eipw_lint::Linter;
 
+use std::collections::BTreeMap;
+use std::io::{BufReader, BufWriter, IsTerminal, Write};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use sysexits::ExitCode;
 
+const DOCS_URL: &str = "https://ethereum.github.io/eipw/";
+
 #[derive(Debug, Parser)]
 #[command(version, about)]
 struct Opts {
@@ -41,6 +52,27 @@ struct Opts {
     #[clap(long, value_enum, default_value_t)]
     format: Format,
 
+    /// When to use colors in text output.
+    #[clap(long, value_enum, default_value_t)]
+    color: Color,
+
+    /// Fail when there are any messages at this level or more severe.
+    #[clap(long, value_enum, default_value_t)]
+    fail_on: FailOn,
+
+    /// Fail when there are more than this many warnings.
+    #[clap(long, value_name = "N")]
+    max_warnings: Option<usize>,
+
+    /// Print counts per lint and file, and the elapsed time, to standard error.
+    #[clap(long, value_enum, num_args(0..=1), require_equals(true), default_missing_value("table"))]
+    summary: Option<Summary>,
+
+    /// Print the time spent in each lint, and in fetching and parsing, to
+    /// standard error.
+    #[clap(long)]
+    timings: bool,
+
     /// Do not enable the default lints.
     #[clap(long)]
     no_default_lints: bool,
@@ -60,6 +92,23 @@ struct Opts {
     /// Path to file defining alternate default lints.
     #[clap(long, short('c'))]
     config: Option<PathBuf>,
+
+    /// Also write a report in another format to a file, like `html=report.html`.
+    #[clap(long, value_name = "FORMAT=PATH", value_parser = parse_output)]
+    output: Vec<(Format, PathBuf)>,
+
+    /// Reuse results for unchanged files from this file, and update it.
+    #[clap(long, value_name = "PATH")]
+    cache: Option<PathBuf>,
+}
+
+fn parse_output(arg: &str) -> Result<(Format, PathBuf), String> {
+    let (format, path) = arg
+        .split_once('=')
+        .ok_or_else(|| "expected `FORMAT=PATH`".to_owned())?;
+
+    let format = Format::from_str(format, true)?;
+    Ok((format, PathBuf::from(path)))
 }
 
 #[derive(Default, ValueEnum, Clone, Debug)]
@@ -67,12 +116,238 @@ enum Format {
     #[default]
     Text,
     Json,
+    Ndjson,
+    Junit,
+    Checkstyle,
+    Gitlab,
+    Markdown,
+    Html,
+    Rdjson,
+    Rdjsonl,
+}
+
+#[derive(Default, ValueEnum, Clone, Copy, Debug)]
+enum Color {
+    /// Use colors when writing to a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    /// Always use colors.
+    Always,
+    /// Never use colors.
+    Never,
+}
+
+impl Color {
+    fn enabled(self) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => {
+                let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+                !no_color && std::io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+#[derive(Default, ValueEnum, Clone, Copy, Debug)]
+enum FailOn {
+    #[default]
+    Error,
+    Warning,
+    Info,
+    Note,
+    Help,
+}
+
+impl FailOn {
+    fn level(self) -> Level {
+        match self {
+            Self::Error => Level::Error,
+            Self::Warning => Level::Warning,
+            Self::Info => Level::Info,
+            Self::Note => Level::Note,
+            Self::Help => Level::Help,
+        }
+    }
+
+    /// Number of messages at or above this level.
+    fn count(self, counts: &Counts) -> usize {
+        let levels = [
+            (Level::Error, counts.error),
+            (Level::Warning, counts.warning),
+            (Level::Info, counts.info),
+            (Level::Note, counts.note),
+            (Level::Help, counts.help),
+        ];
+
+        levels
+            .into_iter()
+            .filter(|(level, _)| *level >= self.level())
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Summary {
+    /// A human readable table.
+    Table,
+    /// A single line JSON object.
+    Json,
+}
+
+fn print_table(kind: &str, rows: &BTreeMap<String, Counts>, total: Option<&Counts>) {
+    let width = rows
+        .keys()
+        .map(|k| k.chars().count())
+        .chain([kind.len(), "total".len()])
+        .max()
+        .unwrap_or_default();
+
+    eprintln!(
+        "{:width$}  {:>6}  {:>8}  {:>5}",
+        kind, "errors", "warnings", "other"
+    );
+
+    let row = |name: &str, c: &Counts| {
+        eprintln!(
+            "{:width$}  {:>6}  {:>8}  {:>5}",
+            name,
+            c.error,
+            c.warning,
+            c.info + c.note + c.help,
+        )
+    };
+
+    for (name, counts) in rows {
+        row(name, counts);
+    }
+
+    if let Some(total) = total {
+        row("total", total);
+    }
+
+    eprintln!();
+}
+
+fn print_summary(
+    summary: Summary,
+    total: Counts,
+    by_lint: BTreeMap<String, Counts>,
+    by_origin: BTreeMap<String, Counts>,
+    files: usize,
+    elapsed: Duration,
+) {
+    match summary {
+        Summary::Table => {
+            print_table("lint", &by_lint, None);
+            print_table("file", &by_origin, Some(&total));
+            eprintln!("checked {} files in {:.2}s", files, elapsed.as_secs_f64());
+        }
+        Summary::Json => {
+            let value = serde_json::json!({
+                "summary": {
+                    "files_checked": files,
+                    "elapsed_seconds": elapsed.as_secs_f64(),
+                    "total": total,
+                    "lints": by_lint,
+                    "files": by_origin,
+                },
+            });
+            eprintln!("{}", value);
+        }
+    }
+}
+
+fn print_timings(timings: &Timings) {
+    let mut lints: Vec<_> = timings.lints().into_iter().collect();
+    lints.sort_by(|(a_slug, a), (b_slug, b)| b.total().cmp(&a.total()).then(a_slug.cmp(b_slug)));
+
+    let width = lints
+        .iter()
+        .map(|(slug, _)| slug.chars().count())
+        .chain(["lint".len(), "total".len()])
+        .max()
+        .unwrap_or_default();
+
+    let ms = |d: Duration| format!("{:.3}", d.as_secs_f64() * 1000.0);
+
+    eprintln!(
+        "{:width$}  {:>12}  {:>14}  {:>10}  {:>10}",
+        "lint", "prepare (ms)", "resources (ms)", "lint (ms)", "total (ms)"
+    );
+
+    let mut total = Duration::ZERO;
+    for (slug, timing) in &lints {
+        total += timing.total();
+        eprintln!(
+            "{:width$}  {:>12}  {:>14}  {:>10}  {:>10}",
+            slug,
+            ms(timing.prepare),
+            ms(timing.find_resources),
+            ms(timing.lint),
+            ms(timing.total()),
+        );
+    }
+
+    eprintln!(
+        "{:width$}  {:>12}  {:>14}  {:>10}  {:>10}",
+        "total",
+        "",
+        "",
+        "",
+        ms(total)
+    );
+    eprintln!();
+    eprintln!("fetching: {}ms", ms(timings.fetch()));
+    eprintln!("parsing: {}ms", ms(timings.parse()));
+    eprintln!();
+}
+
+#[derive(Debug)]
+enum Output {
+    Stdout(std::io::Stdout),
+    File(std::fs::File),
+}
+
+impl Output {
+    fn try_clone(&self) -> std::io::Result<Self> {
+        match self {
+            Self::Stdout(_) => Ok(Self::Stdout(std::io::stdout())),
+            Self::File(f) => f.try_clone().map(Self::File),
+        }
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Stdout(s) => s.write(buf),
+            Self::File(f) => f.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Stdout(s) => s.flush(),
+            Self::File(f) => f.flush(),
+        }
+    }
 }
 
 #[derive(Debug)]
 enum EitherReporter {
     Text(Text<String>),
     Json(Json),
+    Ndjson(Ndjson<Output>),
+    Junit(Junit),
+    Checkstyle(Checkstyle),
+    Gitlab(Gitlab),
+    Markdown(Markdown),
+    Html(Html),
+    Rdjson(Rdjson),
+    Rdjsonl(Rdjsonl<Output>),
 }
 
 impl Reporter for EitherReporter {
@@ -80,7 +355,68 @@ impl Reporter for EitherReporter {
         match self {
             Self::Text(s) => s.report(snippet),
             Self::Json(j) => j.report(snippet),
+            Self::Ndjson(j) => j.report(snippet),
+            Self::Junit(j) => j.report(snippet),
+            Self::Checkstyle(c) => c.report(snippet),
+            Self::Gitlab(g) => g.report(snippet),
+            Self::Markdown(m) => m.report(snippet),
+            Self::Html(h) => h.report(snippet),
+            Self::Rdjson(r) => r.report(snippet),
+            Self::Rdjsonl(r) => r.report(snippet),
+        }
+    }
+}
+
+impl EitherReporter {
+    fn new(
+        format: &Format,
+        output: &Output,
+        color: bool,
+        sources: &[PathBuf],
+    ) -> std::io::Result<Self> {
+        let reporter = match format {
+            Format::Json => Self::Json(Json::default()),
+            Format::Ndjson => Self::Ndjson(Ndjson::new(output.try_clone()?)),
+            Format::Text => Self::Text(Text::new(String::new()).with_color(color)),
+            Format::Junit => {
+                let junit = Junit::default();
+                for source in sources {
+                    junit.add_source(&source.to_string_lossy());
+                }
+                Self::Junit(junit)
+            }
+            Format::Checkstyle => Self::Checkstyle(Checkstyle::default()),
+            Format::Gitlab => Self::Gitlab(Gitlab::default()),
+            Format::Markdown => Self::Markdown(Markdown::default()),
+            Format::Html => Self::Html(
+                Html::default()
+                    .with_stylesheet(include_str!("../docs/main.css"))
+                    .with_docs_url(DOCS_URL),
+            ),
+            Format::Rdjson => Self::Rdjson(Rdjson::default().with_docs_url(DOCS_URL)),
+            Format::Rdjsonl => {
+                Self::Rdjsonl(Rdjsonl::new(output.try_clone()?).with_docs_url(DOCS_URL))
+            }
+        };
+
+        Ok(reporter)
+    }
+
+    fn write_to(self, mut output: Output) -> std::io::Result<()> {
+        match self {
+            Self::Json(j) => serde_json::to_writer_pretty(&mut output, &j)?,
+            Self::Ndjson(_) => (),
+            Self::Text(t) => write!(output, "{}", t.into_inner())?,
+            Self::Junit(j) => write!(output, "{}", j.into_xml())?,
+            Self::Checkstyle(c) => write!(output, "{}", c.into_xml())?,
+            Self::Gitlab(g) => serde_json::to_writer_pretty(&mut output, &g)?,
+            Self::Markdown(m) => write!(output, "{}", m.into_markdown())?,
+            Self::Html(h) => write!(output, "{}", h.into_html())?,
+            Self::Rdjson(r) => serde_json::to_writer_pretty(&mut output, &r)?,
+            Self::Rdjsonl(_) => (),
         }
+
+        output.flush()
     }
 }
 
@@ -93,7 +429,8 @@ fn defaults() {
 }
 
 fn list_lints() {
-    let options = DefaultOptions::<String>::default();
+    let mut options = DefaultOptions::<String>::default();
+    options.expand_preamble();
     println!("Available lints:");
 
     for (slug, _) in options.lints {
@@ -117,6 +454,49 @@ async fn read_config(path: &Path) -> Result<DefaultOptions, toml::de::Error> {
     toml::from_str(&contents)
 }
 
+fn load_cache<C: serde::Serialize>(path: &Path, config: &C) -> Cache {
+    let mut cache = Cache::new(config).expect("configuration should serialize");
+
+    let loaded = match std::fs::File::open(path) {
+        Ok(f) => cache.load(BufReader::new(f)).map_err(std::io::Error::from),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    };
+
+    if let Err(e) = loaded {
+        eprintln!("ignoring cache `{}`: {}", path.display(), e);
+    }
+
+    cache
+}
+
+fn save_cache(path: &Path, cache: &Cache) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(std::fs::File::create(path)?);
+    cache.save(&mut writer)?;
+    writer.flush()
+}
+
+/// The I/O error behind `error`, and its kind, if a reporter failed to write.
+fn report_io_error(
+    error: &eipw_lint::Error,
+) -> Option<(&(dyn std::error::Error + 'static), std::io::ErrorKind)> {
+    let mut source = std::error::Error::source(error);
+
+    while let Some(e) = source {
+        if let Some(reporter) = e.downcast_ref::<ReporterError>() {
+            let cause = std::error::Error::source(reporter)?;
+            let kind = match cause.downcast_ref::<serde_json::Error>() {
+                Some(json) => json.io_error_kind(),
+                None => cause.downcast_ref::<std::io::Error>().map(|io| io.kind()),
+            };
+            return kind.map(|k| (cause, k));
+        }
+        source = e.source();
+    }
+
+    None
+}
+
 async fn try_read_config(path: &Path) -> Result<DefaultOptions, ExitCode> {
     let error = match read_config(path).await {
         Ok(o) => return Ok(o),
@@ -190,17 +570,35 @@ async fn run(opts: Opts) -> Result<(), ExitCode> {
         return Ok(());
     }
 
-    let stdout = std::io::stdout();
-
+    let started = Instant::now();
     let sources = collect_sources(opts.sources).await.unwrap();
 
-    let reporter = match opts.format {
-        Format::Json => EitherReporter::Json(Json::default()),
-        Format::Text => EitherReporter::Text(Text::default()),
-    };
+    let stdout = Output::Stdout(std::io::stdout());
+    let color = opts.color.enabled();
+    let reporter = EitherReporter::new(&opts.format, &stdout, color, &sources).unwrap();
+
+    let mut outputs = vec![stdout];
+    let mut reporters = vec![reporter];
+
+    for (format, path) in &opts.output {
+        let created = std::fs::File::create(path)
+            .map(Output::File)
+            .and_then(|o| Ok((EitherReporter::new(format, &o, false, &sources)?, o)));
+
+        match created {
+            Ok((reporter, output)) => {
+                reporters.push(reporter);
+                outputs.push(output);
+            }
+            Err(e) => {
+                eprintln!("couldn't create `{}`: {}", path.display(), e);
+                return Err(ExitCode::CantCreat);
+            }
+        }
+    }
 
-    let reporter = AdditionalHelp::new(reporter, |t: &str| {
-        Ok(format!("see https://ethereum.github.io/eipw/{}/", t))
+    let reporter = AdditionalHelp::new(Tee::new(reporters), |t: &str| {
+        Ok(format!("see {}{}/", DOCS_URL, t))
     });
     let reporter = Count::new(reporter);
 
@@ -213,6 +611,19 @@ async fn run(opts: Opts) -> Result<(), ExitCode> {
         linter = Linter::new(reporter);
     }
 
+    options.expand_preamble();
+
+    let cache = opts.cache.as_deref().map(|path| {
+        let config = (
+            &options,
+            opts.no_default_lints,
+            &opts.allow,
+            &opts.warn,
+            &opts.deny,
+        );
+        load_cache(path, &config)
+    });
+
     if opts.no_default_lints {
         linter = linter.clear_lints();
     }
@@ -239,17 +650,89 @@ async fn run(opts: Opts) -> Result<(), ExitCode> {
         linter = linter.check_file(source);
     }
 
-    let reporter = linter.run().await.unwrap();
+    if let Some(ref cache) = cache {
+        linter = linter.use_cache(cache);
+    }
+
+    let timings = Timings::default();
+    if opts.timings {
+        linter = linter.record_timings(&timings);
+    }
+
+    let result = linter.run().await;
+
+    // Streaming reporters write while linting, so stdout may already be gone.
+    if let Err(ref e) = result {
+        match report_io_error(e) {
+            Some((_, std::io::ErrorKind::BrokenPipe)) => return Ok(()),
+            Some((e, _)) => {
+                eprintln!("couldn't write report: {}", e);
+                return Err(ExitCode::IoErr);
+            }
+            None => (),
+        }
+    }
+
+    let reporter = result.unwrap();
+
+    let elapsed = started.elapsed();
+    let counts = reporter.counts();
+    let by_lint = reporter.counts_by_lint();
+    let by_origin = reporter.counts_by_origin();
+
+    let reporters = reporter.into_inner().into_inner().into_inner();
+    for (reporter, output) in reporters.into_iter().zip(outputs) {
+        match reporter.write_to(output) {
+            Ok(()) => (),
+            Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => return Ok(()),
+            Err(e) => {
+                eprintln!("couldn't write report: {}", e);
+                return Err(ExitCode::IoErr);
+            }
+        }
+    }
+
+    if let (Some(path), Some(cache)) = (&opts.cache, &cache) {
+        if let Err(e) = save_cache(path, cache) {
+            eprintln!("couldn't write cache `{}`: {}", path.display(), e);
+            return Err(ExitCode::IoErr);
+        }
+    }
+
+    if opts.timings {
+        print_timings(&timings);
+    }
+
+    if let Some(summary) = opts.summary {
+        print_summary(summary, counts, by_lint, by_origin, sources.len(), elapsed);
+    }
 
-    let n_errors = reporter.counts().error;
+    let mut failed = false;
+
+    let n_failures = opts.fail_on.count(&counts);
+    if n_failures > 0 {
+        failed = true;
+        match opts.fail_on {
+            FailOn::Error => eprintln!("validation failed with {} errors :(", n_failures),
+            level => eprintln!(
+                "validation failed with {} messages at level `{}` or above :(",
+                n_failures,
+                level.to_possible_value().unwrap().get_name(),
+            ),
+        }
+    }
 
-    match reporter.into_inner().into_inner() {
-        EitherReporter::Json(j) => serde_json::to_writer_pretty(&stdout, &j).unwrap(),
-        EitherReporter::Text(t) => print!("{}", t.into_inner()),
+    if let Some(max) = opts.max_warnings {
+        if counts.warning > max {
+            failed = true;
+            eprintln!(
+                "validation failed with {} warnings, more than the maximum of {} :(",
+                counts.warning, max
+            );
+        }
     }
 
-    if n_errors > 0 {
-        eprintln!("validation failed with {} errors :(", n_errors);
+    if failed {
         Err(ExitCode::DataErr)
     } else {
         Ok(())